@@ -27,9 +27,9 @@ fn criterion_benchmark(c: &mut Criterion) {
                 }
 
                 points.shuffle(&mut rng);
-                let (_v, _i) = ConvexHull::try_new(&points, None)
+                ConvexHull::try_new(&points, None)
                     .unwrap()
-                    .vertices_indices();
+                    .vertices_indices()
             });
         });
     });
@@ -0,0 +1,184 @@
+//! Diameters and widths of convex hulls, from their antipodal pairs of features.
+
+use glam::{DVec3, IVec3};
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::collision::project;
+use crate::minkowski::{arcs_intersect, edge_arcs};
+use crate::ConvexHull;
+
+/// A pair of features of a [`ConvexHull`] on two parallel supporting planes,
+/// as returned by [`ConvexHull::width`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AntipodalPair {
+    /// The key of a face, and the index of the vertex furthest behind it.
+    FaceVertex {
+        /// The key of the face.
+        face: usize,
+        /// The index of the vertex in [`ConvexHull::points`].
+        vertex: usize,
+    },
+    /// Two edges, each as the indices of its vertices in [`ConvexHull::points`].
+    EdgeEdge {
+        /// The vertex indices of the two edges.
+        edges: [[usize; 2]; 2],
+    },
+}
+
+impl ConvexHull {
+    /// Computes the diameter of the convex hull, the largest distance between two of its vertices,
+    /// along with the indices of these vertices in [`ConvexHull::points`].
+    #[must_use]
+    pub fn diameter(&self) -> (f64, [usize; 2]) {
+        let vertices: Vec<usize> = self.vertex_indices().into_iter().collect();
+
+        let mut diameter = (0.0, [vertices[0], vertices[0]]);
+        for (n, i) in vertices.iter().enumerate() {
+            for j in &vertices[n + 1..] {
+                let distance_squared = self.points[*i].distance_squared(self.points[*j]);
+                if distance_squared > diameter.0 {
+                    diameter = (distance_squared, [*i, *j]);
+                }
+            }
+        }
+
+        (diameter.0.sqrt(), diameter.1)
+    }
+
+    /// Computes the width of the convex hull, the smallest distance between two parallel planes
+    /// enclosing it, along with the unit normal of the planes and the features of the convex hull
+    /// touching them.
+    ///
+    /// The planes touch the convex hull either at a face and a vertex, or at two edges.
+    /// Every face is tried with the vertex furthest behind it, and every pair of edges
+    /// with opposite supporting directions, as found by their arcs on the Gauss map crossing.
+    /// Each face is compared with every vertex, but only arcs that are near each other
+    /// on the Gauss map are tested for crossings, rather than every pair of edges.
+    #[must_use]
+    pub fn width(&self) -> (f64, DVec3, AntipodalPair) {
+        let vertex_indices: Vec<usize> = self.vertex_indices().into_iter().collect();
+        let vertices: Vec<DVec3> = vertex_indices.iter().map(|i| self.points[*i]).collect();
+
+        // The extent along a direction is never smaller than the width,
+        // even if the direction is inaccurate for thin faces.
+        let extent = |direction: DVec3| {
+            let (min, max) = project(&vertices, direction);
+            max - min
+        };
+
+        let mut width: Option<(f64, DVec3, AntipodalPair)> = None;
+        let mut update = |direction: DVec3, pair: AntipodalPair| {
+            let Some(direction) = direction.try_normalize() else {
+                return;
+            };
+            let distance = extent(direction);
+            if width.is_none_or(|(width, ..)| distance < width) {
+                width = Some((distance, direction, pair));
+            }
+        };
+
+        for (key, face) in self.faces() {
+            let vertex = vertex_indices
+                .iter()
+                .copied()
+                .min_by(|i, j| {
+                    let (d_i, d_j) = (
+                        face.normal.dot(self.points[*i]),
+                        face.normal.dot(self.points[*j]),
+                    );
+                    d_i.total_cmp(&d_j)
+                })
+                .unwrap();
+            update(face.normal, AntipodalPair::FaceVertex { face: key, vertex });
+        }
+
+        // The reflected convex hull has the same vertex indices, with opposite arcs.
+        let arcs = edge_arcs(self);
+        let reflected_arcs = edge_arcs(&self.reflected());
+        let grid = ArcGrid::new(&reflected_arcs);
+        for ([a0, a1], arc_a) in &arcs {
+            for ([b0, b1], arc_b) in grid.candidates(*arc_a).map(|i| &reflected_arcs[i]) {
+                if arcs_intersect(*arc_a, *arc_b) {
+                    let edge_a = self.points[*a1] - self.points[*a0];
+                    let edge_b = self.points[*b1] - self.points[*b0];
+                    let edges = [[*a0, *a1], [*b0, *b1]];
+                    update(edge_a.cross(edge_b), AntipodalPair::EdgeEdge { edges });
+                }
+            }
+        }
+
+        width.expect("a convex hull has faces")
+    }
+}
+
+/// The arcs on the Gauss map, bucketed by the cells of a uniform grid over the cube around the unit sphere
+/// that their bounding boxes overlap.
+struct ArcGrid {
+    /// The number of cells along each axis.
+    resolution: i32,
+    /// The indices of the arcs overlapping each cell.
+    cells: HashMap<IVec3, Vec<usize>>,
+}
+
+impl ArcGrid {
+    /// Buckets the arcs, with a resolution that keeps the number of arcs in each cell small
+    /// for arcs spread evenly over the unit sphere.
+    fn new<T>(arcs: &[(T, [DVec3; 2])]) -> Self {
+        let resolution = ((arcs.len() as f64).sqrt() / 2.0).clamp(1.0, 256.0) as i32;
+        let mut grid = Self {
+            resolution,
+            cells: HashMap::new(),
+        };
+        for (i, (_, arc)) in arcs.iter().enumerate() {
+            for cell in grid.cells_around(*arc) {
+                grid.cells.entry(cell).or_default().push(i);
+            }
+        }
+        grid
+    }
+
+    /// Returns the indices of the arcs that may cross the given arc, in increasing order.
+    fn candidates(&self, arc: [DVec3; 2]) -> impl Iterator<Item = usize> {
+        let candidates: BTreeSet<usize> = self
+            .cells_around(arc)
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.into_iter()
+    }
+
+    /// Returns the cells overlapping the bounding box of the arc.
+    ///
+    /// An arc shorter than half a great circle stays within the triangle between its ends
+    /// and the point where the tangents at its ends meet, which is infinitely far away
+    /// for half a great circle. The box is enlarged slightly for round-off errors,
+    /// and clipped to the cube around the unit sphere.
+    fn cells_around(&self, [p, q]: [DVec3; 2]) -> impl Iterator<Item = IVec3> {
+        let apex = (p + q) / (1.0 + p.dot(q));
+        let margin = DVec3::splat(ARC_MARGIN);
+        let (min, max) = if apex.is_finite() {
+            (p.min(q).min(apex) - margin, p.max(q).max(apex) + margin)
+        } else {
+            (DVec3::NEG_ONE, DVec3::ONE)
+        };
+
+        let cell = |corner: DVec3| {
+            let scaled =
+                (corner.clamp(DVec3::NEG_ONE, DVec3::ONE) + 1.0) / 2.0 * self.resolution as f64;
+            scaled
+                .floor()
+                .as_ivec3()
+                .min(IVec3::splat(self.resolution - 1))
+        };
+        let (min, max) = (cell(min), cell(max));
+
+        (min.x..=max.x).flat_map(move |x| {
+            (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| IVec3::new(x, y, z)))
+        })
+    }
+}
+
+/// The margin around the bounding boxes of arcs in an [`ArcGrid`], for round-off errors.
+const ARC_MARGIN: f64 = 1e-9;
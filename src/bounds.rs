@@ -0,0 +1,294 @@
+//! Bounding volumes of convex hulls.
+
+use glam::{DMat3, DVec2, DVec3};
+
+use crate::collision::project;
+use crate::polygon::convex_hull_2d;
+use crate::{plane_basis, ConvexHull};
+
+/// The relative tolerance for a point to be considered inside a bounding sphere.
+const SPHERE_TOLERANCE: f64 = 1e-12;
+
+impl ConvexHull {
+    /// Computes the axis-aligned bounding box of the convex hull, as its minimum and maximum corners.
+    #[must_use]
+    pub fn aabb(&self) -> (DVec3, DVec3) {
+        let (min_indices, max_indices) = Self::compute_extremes(&self.points);
+        let min = DVec3::from_array([0, 1, 2].map(|axis| self.points[min_indices[axis]][axis]));
+        let max = DVec3::from_array([0, 1, 2].map(|axis| self.points[max_indices[axis]][axis]));
+        (min, max)
+    }
+
+    /// Computes the smallest sphere enclosing the convex hull, as its center and radius.
+    ///
+    /// This uses Welzl's algorithm on the vertices of the convex hull, in its iterative form
+    /// where every point outside of the current sphere restarts the search with that point
+    /// on the boundary of the sphere. The vertices are visited in a fixed pseudo-random order,
+    /// which keeps the expected running time linear even for sorted points.
+    #[must_use]
+    pub fn bounding_sphere(&self) -> (DVec3, f64) {
+        let mut vertices: Vec<DVec3> = self
+            .vertex_indices()
+            .into_iter()
+            .map(|i| self.points[i])
+            .collect();
+        shuffle(&mut vertices);
+
+        let mut sphere = (vertices[0], 0.0);
+        for i in 1..vertices.len() {
+            if is_in_sphere(sphere, vertices[i]) {
+                continue;
+            }
+            sphere = (vertices[i], 0.0);
+            for j in 0..i {
+                if is_in_sphere(sphere, vertices[j]) {
+                    continue;
+                }
+                sphere = sphere_from_segment(vertices[i], vertices[j]);
+                for k in 0..j {
+                    if is_in_sphere(sphere, vertices[k]) {
+                        continue;
+                    }
+                    sphere = sphere_from_triangle(vertices[i], vertices[j], vertices[k]);
+                    for l in 0..k {
+                        if is_in_sphere(sphere, vertices[l]) {
+                            continue;
+                        }
+                        sphere = sphere_from_tetrahedron(
+                            vertices[i],
+                            vertices[j],
+                            vertices[k],
+                            vertices[l],
+                        );
+                    }
+                }
+            }
+        }
+
+        sphere
+    }
+}
+
+impl ConvexHull {
+    /// Computes an oriented bounding box of the convex hull with a small volume,
+    /// as its center, its axes as the columns of a rotation matrix, and its half-extents along the axes.
+    ///
+    /// Each face normal and edge direction of the convex hull is tried as an axis of the box.
+    /// The convex hull is projected onto the plane perpendicular to the axis, and the smallest rectangle
+    /// around the projection is found with rotating calipers, as one of its sides is flush with
+    /// an edge of the projection. The box with the smallest volume is returned, starting from
+    /// the [axis-aligned bounding box](ConvexHull::aabb), so the result is never larger than it.
+    /// This finds the minimum-volume box whenever one of its faces is flush with a face of the convex hull,
+    /// which is not always the case.
+    #[must_use]
+    pub fn oriented_bounding_box(&self) -> (DVec3, DMat3, DVec3) {
+        let vertices: Vec<DVec3> = self
+            .vertex_indices()
+            .into_iter()
+            .map(|i| self.points[i])
+            .collect();
+
+        let mut axes: Vec<DVec3> = self
+            .faces()
+            .map(|(_, face)| face.normal)
+            .chain(
+                self.edges()
+                    .into_iter()
+                    .map(|([a, b], _)| self.points[b] - self.points[a]),
+            )
+            .filter_map(|axis| {
+                let axis = axis.try_normalize()?;
+                // Opposite directions give the same boxes.
+                let sign = axis.to_array().into_iter().find(|c| *c != 0.0)?.signum();
+                Some(axis * sign)
+            })
+            .collect();
+        axes.sort_by(|a, b| a.to_array().partial_cmp(&b.to_array()).unwrap());
+        axes.dedup_by(|a, b| a.abs_diff_eq(*b, AXIS_TOLERANCE));
+
+        let (min, max) = self.aabb();
+        let mut best = (
+            (max - min).element_product(),
+            ((min + max) / 2.0, DMat3::IDENTITY, (max - min) / 2.0),
+        );
+        for axis in axes {
+            let (u, v) = plane_basis(axis);
+            let projected: Vec<DVec2> = vertices
+                .iter()
+                .map(|p| DVec2::new(p.dot(u), p.dot(v)))
+                .collect();
+            let polygon: Vec<DVec2> = convex_hull_2d(&projected)
+                .into_iter()
+                .map(|i| projected[i])
+                .collect();
+            let Some((direction, min, max)) = min_area_rectangle(&polygon) else {
+                continue;
+            };
+            let (min_z, max_z) = project(&vertices, axis);
+            let (min, max) = (min.extend(min_z), max.extend(max_z));
+
+            let volume = (max - min).element_product();
+            if best.0 <= volume {
+                continue;
+            }
+
+            let x_axis = u * direction.x + v * direction.y;
+            let y_axis = u * -direction.y + v * direction.x;
+            let rotation = DMat3::from_cols(x_axis, y_axis, axis);
+            best = (
+                volume,
+                (rotation * (min + max) / 2.0, rotation, (max - min) / 2.0),
+            );
+        }
+
+        best.1
+    }
+}
+
+/// The tolerance for two unit axes to be considered the same in [`ConvexHull::oriented_bounding_box`].
+const AXIS_TOLERANCE: f64 = 1e-9;
+
+/// Finds the smallest rectangle around a convex polygon in counterclockwise order,
+/// which has a side flush with one of its edges.
+///
+/// The rectangle against each edge touches the polygon at the vertices farthest along the edge,
+/// farthest away from it, and farthest back along it. These vertices only move forward as the edges
+/// turn counterclockwise, so they are tracked with rotating calipers in time linear in the number of vertices.
+///
+/// Returns the unit direction of that edge, and the minimum and maximum corners of the rectangle
+/// in coordinates along the direction and the direction rotated counterclockwise by a right angle.
+fn min_area_rectangle(polygon: &[DVec2]) -> Option<(DVec2, DVec2, DVec2)> {
+    let n = polygon.len();
+    let mut best: Option<(f64, DVec2, DVec2, DVec2)> = None;
+    let mut calipers: Option<[usize; 3]> = None;
+
+    for i in 0..n {
+        let Some(direction) = (polygon[(i + 1) % n] - polygon[i]).try_normalize() else {
+            continue;
+        };
+
+        // Advances an index while the edge after it still points along the axis. Testing the edges
+        // rather than comparing the positions of the vertices keeps this robust for nearly identical
+        // vertices, as the edges of a convex polygon turn in one direction.
+        let advance = |mut index: usize, axis: DVec2| {
+            for _ in 0..n {
+                if (polygon[(index + 1) % n] - polygon[index]).dot(axis) <= 0.0 {
+                    break;
+                }
+                index = (index + 1) % n;
+            }
+            index
+        };
+        let [right, top, left] = match calipers {
+            Some([right, top, left]) => [
+                advance(right, direction),
+                advance(top, direction.perp()),
+                advance(left, -direction),
+            ],
+            None => {
+                let right = advance(i, direction);
+                let top = advance(right, direction.perp());
+                [right, top, advance(top, -direction)]
+            }
+        };
+        calipers = Some([right, top, left]);
+
+        // The polygon is to the left of its counterclockwise edges, so the edge itself is the bottom side.
+        let coordinates = |p: DVec2| DVec2::new(p.dot(direction), p.dot(direction.perp()));
+        let min = DVec2::new(coordinates(polygon[left]).x, coordinates(polygon[i]).y);
+        let max = DVec2::new(coordinates(polygon[right]).x, coordinates(polygon[top]).y);
+
+        let area = (max - min).element_product();
+        if best.is_none_or(|(best, ..)| area < best) {
+            best = Some((area, direction, min, max));
+        }
+    }
+
+    best.map(|(_, direction, min, max)| (direction, min, max))
+}
+
+/// Shuffles the items with the Fisher–Yates algorithm, drawing from a xorshift generator with a fixed seed
+/// so that the result is deterministic.
+fn shuffle<T>(items: &mut [T]) {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        items.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+/// Checks whether the point is inside the sphere, allowing for round-off errors.
+fn is_in_sphere((center, radius): (DVec3, f64), point: DVec3) -> bool {
+    let tolerance = SPHERE_TOLERANCE * (radius + center.abs().max_element()).max(f64::MIN_POSITIVE);
+    center.distance(point) <= radius + tolerance
+}
+
+/// Returns the smallest sphere with both points on its boundary.
+fn sphere_from_segment(a: DVec3, b: DVec3) -> (DVec3, f64) {
+    let center = a.midpoint(b);
+    (center, center.distance(a).max(center.distance(b)))
+}
+
+/// Returns the smallest sphere with all three points on its boundary, which is centered
+/// on the circumcenter of the triangle.
+///
+/// If the points are on a line, the smallest sphere containing them is returned instead.
+fn sphere_from_triangle(a: DVec3, b: DVec3, c: DVec3) -> (DVec3, f64) {
+    let ab = b - a;
+    let ac = c - a;
+    let normal = ab.cross(ac);
+    let denominator = 2.0 * normal.length_squared();
+
+    if denominator == 0.0 {
+        return [(a, b), (a, c), (b, c)]
+            .map(|(p, q)| sphere_from_segment(p, q))
+            .into_iter()
+            .max_by(|s1, s2| s1.1.total_cmp(&s2.1))
+            .unwrap();
+    }
+
+    let offset = (normal.cross(ab) * ac.length_squared() + ac.cross(normal) * ab.length_squared())
+        / denominator;
+    let center = a + offset;
+    let radius = [a, b, c]
+        .map(|p| center.distance(p))
+        .into_iter()
+        .fold(0.0, f64::max);
+    (center, radius)
+}
+
+/// Returns the smallest sphere with all four points on its boundary, which is the circumsphere
+/// of the tetrahedron.
+///
+/// If the points are on a plane, the smallest of the spheres around three of the points
+/// that contains the fourth point is returned instead.
+fn sphere_from_tetrahedron(a: DVec3, b: DVec3, c: DVec3, d: DVec3) -> (DVec3, f64) {
+    let edges = DMat3::from_cols(b - a, c - a, d - a).transpose();
+    let determinant = edges.determinant();
+    let scale = (b - a).length() * (c - a).length() * (d - a).length();
+
+    if determinant.abs() <= f64::EPSILON * scale {
+        return [(a, b, c, d), (a, b, d, c), (a, c, d, b), (b, c, d, a)]
+            .map(|(p, q, r, s)| (sphere_from_triangle(p, q, r), s))
+            .into_iter()
+            .filter(|(sphere, s)| is_in_sphere(*sphere, *s))
+            .map(|(sphere, _)| sphere)
+            .min_by(|s1, s2| s1.1.total_cmp(&s2.1))
+            .unwrap_or_else(|| sphere_from_triangle(a, b, c));
+    }
+
+    // The center is equally far from all points: 2 (p - a) · offset = |p - a|² for p in b, c and d.
+    let lengths = DVec3::new(
+        (b - a).length_squared(),
+        (c - a).length_squared(),
+        (d - a).length_squared(),
+    );
+    let center = a + edges.inverse() * lengths / 2.0;
+    let radius = [a, b, c, d]
+        .map(|p| center.distance(p))
+        .into_iter()
+        .fold(0.0, f64::max);
+    (center, radius)
+}
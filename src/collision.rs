@@ -0,0 +1,334 @@
+//! Overlap tests and distances between convex hulls.
+
+use glam::{DAffine3, DVec3};
+
+use crate::minkowski::{arcs_intersect, edge_arcs, hull_vertices};
+use crate::ConvexHull;
+
+/// The relative tolerance for the distance to stop improving in [`ConvexHull::distance`].
+const DISTANCE_TOLERANCE: f64 = 1e-12;
+
+/// The maximum number of iterations in [`ConvexHull::distance`], which only matters
+/// when round-off errors keep the closest point from converging.
+const MAX_DISTANCE_ITERATIONS: usize = 128;
+
+/// The contact between two overlapping convex hulls, as returned by [`ConvexHull::contact`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    /// The unit direction in which the second convex hull has to move to separate from the first.
+    pub normal: DVec3,
+    /// How far the second convex hull has to move along the normal to separate from the first.
+    pub depth: f64,
+}
+
+impl ConvexHull {
+    /// Checks whether the two convex hulls overlap. Convex hulls that only touch do not overlap.
+    #[must_use]
+    pub fn overlaps(&self, other: &ConvexHull) -> bool {
+        self.contact(other).is_some()
+    }
+
+    /// Computes the penetration depth and contact normal of two overlapping convex hulls,
+    /// or returns `None` if they do not overlap.
+    ///
+    /// This uses the separating axis theorem. The candidate axes are the face normals
+    /// of both convex hulls, and the cross products of the pairs of edges that form a face
+    /// of the Minkowski difference, which are found by the arcs of the edges crossing on the Gauss map.
+    /// The axis along which the convex hulls overlap the least gives the contact normal.
+    #[must_use]
+    pub fn contact(&self, other: &ConvexHull) -> Option<Contact> {
+        let vertices_a = hull_vertices(self);
+        let vertices_b = hull_vertices(other);
+
+        let mut axes: Vec<DVec3> = self
+            .faces()
+            .chain(other.faces())
+            .map(|(_, face)| face.normal.normalize_or_zero())
+            .collect();
+
+        let arcs_a = edge_arcs(self);
+        let arcs_b = edge_arcs(&other.reflected());
+        for ([a0, a1], arc_a) in &arcs_a {
+            for ([b0, b1], arc_b) in &arcs_b {
+                if arcs_intersect(*arc_a, *arc_b) {
+                    let edge_a = self.points[*a1] - self.points[*a0];
+                    let edge_b = other.points[*b1] - other.points[*b0];
+                    axes.push(edge_a.cross(edge_b).normalize_or_zero());
+                }
+            }
+        }
+
+        let mut contact: Option<Contact> = None;
+        for axis in axes {
+            if axis == DVec3::ZERO {
+                continue;
+            }
+
+            let (min_a, max_a) = project(&vertices_a, axis);
+            let (min_b, max_b) = project(&vertices_b, axis);
+
+            // How far the second convex hull has to move along the axis, or against it, to separate.
+            let forward = max_a - min_b;
+            let backward = max_b - min_a;
+            if forward <= 0.0 || backward <= 0.0 {
+                return None;
+            }
+
+            let (normal, depth) = if forward <= backward {
+                (axis, forward)
+            } else {
+                (-axis, backward)
+            };
+            if contact.is_none_or(|contact| depth < contact.depth) {
+                contact = Some(Contact { normal, depth });
+            }
+        }
+
+        contact
+    }
+}
+
+impl ConvexHull {
+    /// Computes the distance between two convex hulls, along with the closest point on each of them.
+    ///
+    /// This uses the GJK algorithm on the support points of the convex hulls. If the convex hulls
+    /// overlap, the distance is zero and both points are the same point inside both of them.
+    #[must_use]
+    pub fn distance(a: &ConvexHull, b: &ConvexHull) -> (f64, DVec3, DVec3) {
+        Self::distance_with_transform(a, b, &DAffine3::IDENTITY)
+    }
+
+    /// Computes the distance between two convex hulls, along with the closest point on each of them,
+    /// where the second convex hull is first transformed by the given transform.
+    ///
+    /// The closest point on the second convex hull is returned after the transform.
+    /// See [`ConvexHull::distance`].
+    #[must_use]
+    pub fn distance_with_transform(
+        a: &ConvexHull,
+        b: &ConvexHull,
+        transform: &DAffine3,
+    ) -> (f64, DVec3, DVec3) {
+        // The support point of a linearly transformed convex hull is the transformed support point
+        // in the direction transformed by the transpose.
+        let transpose = transform.matrix3.transpose();
+        let support = |direction: DVec3| {
+            let point_a = a.support_point(direction);
+            let point_b = transform.transform_point3(b.support_point(transpose * -direction));
+            (point_a, point_b)
+        };
+
+        gjk(support)
+    }
+
+    /// Computes the distance from the point to the convex hull, along with the closest point on it.
+    pub(crate) fn distance_to_point(&self, point: DVec3) -> (f64, DVec3) {
+        let (distance, closest, _) = gjk(|direction| (self.support_point(direction), point));
+        (distance, closest)
+    }
+}
+
+/// Computes the distance between two convex sets with the GJK algorithm, along with the closest point
+/// on each of them.
+///
+/// The `support` function returns the point furthest in a direction on the first set,
+/// and the point furthest in the opposite direction on the second set.
+pub(crate) fn gjk(support: impl Fn(DVec3) -> (DVec3, DVec3)) -> (f64, DVec3, DVec3) {
+    // The simplex of points on the Minkowski difference, along with the points they come from.
+    let mut simplex: Vec<(DVec3, DVec3)> = vec![support(DVec3::X)];
+    let mut weights = vec![1.0];
+    let mut closest = simplex[0].0 - simplex[0].1;
+
+    for _ in 0..MAX_DISTANCE_ITERATIONS {
+        let distance_squared = closest.length_squared();
+        if distance_squared == 0.0 {
+            break;
+        }
+
+        let (point_a, point_b) = support(-closest);
+        let point = point_a - point_b;
+
+        // The support point cannot get closer to the origin than the current closest point.
+        if distance_squared - closest.dot(point) <= DISTANCE_TOLERANCE * distance_squared
+            || simplex.iter().any(|(a, b)| *a - *b == point)
+        {
+            break;
+        }
+        simplex.push((point_a, point_b));
+
+        let points: Vec<DVec3> = simplex.iter().map(|(a, b)| *a - *b).collect();
+        let new_weights = closest_to_origin(&points);
+        let new_closest: DVec3 = points.iter().zip(&new_weights).map(|(p, w)| *p * *w).sum();
+        if new_closest.length_squared() >= distance_squared {
+            simplex.pop();
+            break;
+        }
+
+        // Only the points with positive weights span the simplex nearest to the origin.
+        closest = new_closest;
+        weights = new_weights;
+        let mut kept = weights.iter().map(|w| *w > 0.0);
+        simplex.retain(|_| kept.next().unwrap());
+        weights.retain(|w| *w > 0.0);
+
+        if simplex.len() == 4 {
+            // The origin is inside the tetrahedron, so the sets overlap.
+            closest = DVec3::ZERO;
+            break;
+        }
+    }
+
+    let point_a = simplex
+        .iter()
+        .zip(&weights)
+        .map(|((a, _), w)| *a * *w)
+        .sum();
+    let point_b = simplex
+        .iter()
+        .zip(&weights)
+        .map(|((_, b), w)| *b * *w)
+        .sum();
+    (closest.length(), point_a, point_b)
+}
+
+/// Computes the barycentric weights of the point closest to the origin on a simplex
+/// of up to four points.
+fn closest_to_origin(points: &[DVec3]) -> Vec<f64> {
+    match *points {
+        [_] => vec![1.0],
+        [a, b] => closest_on_segment(a, b).to_vec(),
+        [a, b, c] => closest_on_triangle(a, b, c).to_vec(),
+        [a, b, c, d] => closest_on_tetrahedron([a, b, c, d]),
+        _ => unreachable!("a simplex has at most four points"),
+    }
+}
+
+/// Computes the barycentric weights of the point closest to the origin on the segment.
+fn closest_on_segment(a: DVec3, b: DVec3) -> [f64; 2] {
+    let ab = b - a;
+    let t = -a.dot(ab) / ab.length_squared();
+    if t.is_nan() {
+        // The segment is a single point.
+        [1.0, 0.0]
+    } else {
+        let t = t.clamp(0.0, 1.0);
+        [1.0 - t, t]
+    }
+}
+
+/// Computes the barycentric weights of the point closest to the origin on the triangle.
+///
+/// See Christer Ericson. 2005. Real-Time Collision Detection, section 5.1.5.
+fn closest_on_triangle(a: DVec3, b: DVec3, c: DVec3) -> [f64; 3] {
+    let ab = b - a;
+    let ac = c - a;
+
+    let d1 = ab.dot(-a);
+    let d2 = ac.dot(-a);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return [1.0, 0.0, 0.0];
+    }
+
+    let d3 = ab.dot(-b);
+    let d4 = ac.dot(-b);
+    if d3 >= 0.0 && d4 <= d3 {
+        return [0.0, 1.0, 0.0];
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return [1.0 - v, v, 0.0];
+    }
+
+    let d5 = ab.dot(-c);
+    let d6 = ac.dot(-c);
+    if d6 >= 0.0 && d5 <= d6 {
+        return [0.0, 0.0, 1.0];
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return [1.0 - w, 0.0, w];
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return [0.0, 1.0 - w, w];
+    }
+
+    let denominator = va + vb + vc;
+    if denominator == 0.0 {
+        // A degenerate triangle, where the closest point is on one of its edges.
+        let points = [a, b, c];
+        let mut closest = ([0.0; 3], f64::INFINITY);
+        for [i, j] in [[0, 1], [0, 2], [1, 2]] {
+            let [w_i, w_j] = closest_on_segment(points[i], points[j]);
+            let distance_squared = (points[i] * w_i + points[j] * w_j).length_squared();
+            if distance_squared < closest.1 {
+                let mut weights = [0.0; 3];
+                weights[i] = w_i;
+                weights[j] = w_j;
+                closest = (weights, distance_squared);
+            }
+        }
+        return closest.0;
+    }
+
+    let v = vb / denominator;
+    let w = vc / denominator;
+    [1.0 - v - w, v, w]
+}
+
+/// Computes the barycentric weights of the point closest to the origin on the tetrahedron.
+fn closest_on_tetrahedron(points: [DVec3; 4]) -> Vec<f64> {
+    let mut weights = vec![0.0; 4];
+    let mut is_inside = true;
+    let mut best: Option<(f64, Vec<f64>)> = None;
+
+    for opposite in 0..4 {
+        let [i, j, k] = [(opposite + 1) % 4, (opposite + 2) % 4, (opposite + 3) % 4];
+        let normal = (points[j] - points[i]).cross(points[k] - points[i]);
+        let origin_side = normal.dot(-points[i]);
+        let opposite_side = normal.dot(points[opposite] - points[i]);
+
+        // The origin is outside of the face if it is on the other side than the opposite point.
+        // Degenerate tetrahedra are handled through their faces.
+        if opposite_side == 0.0 || origin_side * opposite_side < 0.0 {
+            is_inside = false;
+            let [w_i, w_j, w_k] = closest_on_triangle(points[i], points[j], points[k]);
+            let closest = points[i] * w_i + points[j] * w_j + points[k] * w_k;
+            let distance_squared = closest.length_squared();
+            if best
+                .as_ref()
+                .is_none_or(|(best, _)| distance_squared < *best)
+            {
+                let mut face_weights = vec![0.0; 4];
+                face_weights[i] = w_i;
+                face_weights[j] = w_j;
+                face_weights[k] = w_k;
+                best = Some((distance_squared, face_weights));
+            }
+        } else {
+            weights[opposite] = origin_side / opposite_side;
+        }
+    }
+
+    if is_inside {
+        weights
+    } else {
+        best.unwrap().1
+    }
+}
+
+/// Returns the minimum and maximum of the points projected onto the axis.
+pub(crate) fn project(points: &[DVec3], axis: DVec3) -> (f64, f64) {
+    points
+        .iter()
+        .map(|p| p.dot(axis))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
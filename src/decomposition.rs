@@ -0,0 +1,315 @@
+//! Approximate convex decomposition of triangle meshes.
+
+use glam::DVec3;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{ConvexHull, ErrorKind};
+
+/// The number of evenly spaced cuts tried along each axis when splitting a part of a mesh.
+const CUTS_PER_AXIS: usize = 8;
+
+/// The parameters of [`ConvexHull::decompose`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecompositionParams {
+    /// The number of voxels along the longest side of the bounding box of the mesh.
+    pub resolution: usize,
+    /// The maximum number of convex hulls.
+    pub max_hulls: usize,
+    /// The maximum number of vertices of each convex hull.
+    pub max_vertices: usize,
+    /// The concavity below which a part of the mesh is not split further.
+    ///
+    /// The concavity of a part is the volume of its convex hull that is outside of the mesh,
+    /// relative to the volume of the whole mesh.
+    pub max_concavity: f64,
+}
+
+impl Default for DecompositionParams {
+    fn default() -> Self {
+        Self {
+            resolution: 32,
+            max_hulls: 16,
+            max_vertices: 64,
+            max_concavity: 0.01,
+        }
+    }
+}
+
+/// A part of a voxelized mesh, along with its convex hull.
+struct Part {
+    voxels: Vec<[usize; 3]>,
+    c_hull: ConvexHull,
+    concavity: f64,
+}
+
+impl ConvexHull {
+    /// Decomposes a closed triangle mesh into convex hulls that approximately cover it.
+    ///
+    /// The mesh is voxelized, and the voxels are split recursively along axis-aligned planes,
+    /// always splitting the part with the largest concavity, until every part is convex enough
+    /// or there are as many parts as allowed. Each part is then wrapped in a convex hull,
+    /// which is trimmed to the convex hull of the whole mesh and simplified to the vertex limit.
+    ///
+    /// The mesh should be closed for its inside to be well defined. If no voxel ends up inside it,
+    /// its convex hull is returned as the only part.
+    ///
+    /// ## Errors
+    /// If computing the convex hull of the whole mesh fails, trimming a convex hull to it fails,
+    /// or simplifying a convex hull fails.
+    ///
+    /// ## Panics
+    /// If a triangle refers to a vertex that does not exist.
+    pub fn decompose(
+        vertices: &[DVec3],
+        triangles: &[[usize; 3]],
+        params: &DecompositionParams,
+    ) -> Result<Vec<Self>, ErrorKind> {
+        let mesh_hull = ConvexHull::try_new(vertices, None)?;
+
+        let grid = VoxelGrid::new(vertices, params.resolution.max(1));
+        let voxels = grid.voxelize(vertices, triangles);
+        if voxels.is_empty() {
+            return Ok(vec![mesh_hull.limit_vertices(params.max_vertices)?]);
+        }
+        let mesh_volume = voxels.len() as f64 * grid.voxel_volume();
+
+        let mut parts = Vec::new();
+        if let Some(part) = grid.part(voxels, mesh_volume) {
+            parts.push(part);
+        }
+
+        while parts.len() < params.max_hulls.max(1) {
+            // Split the most concave part that can still be split.
+            let Some((index, children)) = parts
+                .iter()
+                .enumerate()
+                .filter(|(_, part)| part.concavity > params.max_concavity)
+                .max_by(|(_, a), (_, b)| a.concavity.total_cmp(&b.concavity))
+                .and_then(|(index, part)| Some((index, grid.split(part, mesh_volume)?)))
+            else {
+                break;
+            };
+
+            parts.swap_remove(index);
+            parts.extend(children);
+        }
+
+        parts
+            .into_iter()
+            .map(|part| {
+                let c_hull =
+                    ConvexHull::intersection(&part.c_hull, &mesh_hull)?.unwrap_or(part.c_hull);
+                c_hull.limit_vertices(params.max_vertices)
+            })
+            .collect()
+    }
+
+    /// Simplifies the convex hull if it has more than `max_vertices` vertices.
+    fn limit_vertices(self, max_vertices: usize) -> Result<Self, ErrorKind> {
+        if self.vertex_indices().len() > max_vertices {
+            self.simplify(max_vertices)
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+/// A uniform grid of cubic voxels over the bounding box of a mesh.
+struct VoxelGrid {
+    origin: DVec3,
+    voxel_size: f64,
+    dimensions: [usize; 3],
+}
+
+impl VoxelGrid {
+    fn new(vertices: &[DVec3], resolution: usize) -> Self {
+        let (min, max) = vertices
+            .iter()
+            .fold((DVec3::INFINITY, DVec3::NEG_INFINITY), |(min, max), p| {
+                (min.min(*p), max.max(*p))
+            });
+        let voxel_size = (max - min).max_element() / resolution as f64;
+        let dimensions = ((max - min) / voxel_size)
+            .ceil()
+            .max(DVec3::ONE)
+            .to_array()
+            .map(|d| d as usize);
+
+        Self {
+            origin: min,
+            voxel_size,
+            dimensions,
+        }
+    }
+
+    fn voxel_volume(&self) -> f64 {
+        self.voxel_size.powi(3)
+    }
+
+    /// Returns the voxels whose centers are inside the mesh, along with the voxels containing
+    /// its vertices, so that thin features are not lost entirely.
+    ///
+    /// Rays are cast along the x-axis through the centers of each row of voxels, and the voxels
+    /// between each pair of crossings with the mesh are inside.
+    fn voxelize(&self, vertices: &[DVec3], triangles: &[[usize; 3]]) -> Vec<[usize; 3]> {
+        let [nx, ny, nz] = self.dimensions;
+        let mut inside = HashSet::new();
+
+        // Slight and unequal offsets keep the rays from passing exactly through the edges
+        // of the triangles in axis-aligned meshes.
+        let y_offset = self.voxel_size * 1e-6 * std::f64::consts::PI;
+        let z_offset = self.voxel_size * 1e-6 * std::f64::consts::E;
+
+        let mut crossings = Vec::new();
+        for j in 0..ny {
+            for k in 0..nz {
+                let y = self.origin.y + (j as f64 + 0.5) * self.voxel_size + y_offset;
+                let z = self.origin.z + (k as f64 + 0.5) * self.voxel_size + z_offset;
+
+                crossings.clear();
+                for triangle in triangles {
+                    let [a, b, c] = triangle.map(|i| vertices[i]);
+                    if let Some(x) = ray_crossing(a, b, c, y, z) {
+                        crossings.push(x);
+                    }
+                }
+                crossings.sort_by(f64::total_cmp);
+
+                for pair in crossings.chunks_exact(2) {
+                    let first = ((pair[0] - self.origin.x) / self.voxel_size - 0.5)
+                        .ceil()
+                        .max(0.0);
+                    let last = ((pair[1] - self.origin.x) / self.voxel_size - 0.5).floor();
+                    if last < first {
+                        continue;
+                    }
+                    for i in first as usize..=(last as usize).min(nx - 1) {
+                        inside.insert([i, j, k]);
+                    }
+                }
+            }
+        }
+
+        for vertex in vertices {
+            inside.insert(self.voxel_of(*vertex));
+        }
+
+        let mut voxels: Vec<[usize; 3]> = inside.into_iter().collect();
+        voxels.sort_unstable();
+        voxels
+    }
+
+    fn voxel_of(&self, point: DVec3) -> [usize; 3] {
+        let index = ((point - self.origin) / self.voxel_size)
+            .floor()
+            .max(DVec3::ZERO);
+        [0, 1, 2].map(|axis| (index[axis] as usize).min(self.dimensions[axis] - 1))
+    }
+
+    /// Creates a part from the voxels, or returns `None` if their convex hull cannot be computed.
+    fn part(&self, voxels: Vec<[usize; 3]>, mesh_volume: f64) -> Option<Part> {
+        let c_hull = ConvexHull::try_new(&self.hull_points(&voxels), None).ok()?;
+        let volume = voxels.len() as f64 * self.voxel_volume();
+        let concavity = (c_hull.clone().volume() - volume).max(0.0) / mesh_volume;
+        Some(Part {
+            voxels,
+            c_hull,
+            concavity,
+        })
+    }
+
+    /// Returns the corners of the voxels that can be vertices of the convex hull of the voxels.
+    ///
+    /// A voxel can only have a corner on the convex hull if it is the first or the last voxel
+    /// in its row along one of the axes.
+    fn hull_points(&self, voxels: &[[usize; 3]]) -> Vec<DVec3> {
+        let mut extremes: HashSet<[usize; 3]> = HashSet::new();
+        for axis in 0..3 {
+            let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+            let mut rows: HashMap<(usize, usize), ([usize; 3], [usize; 3])> = HashMap::new();
+            for voxel in voxels {
+                let row = rows.entry((voxel[u], voxel[v])).or_insert((*voxel, *voxel));
+                if voxel[axis] < row.0[axis] {
+                    row.0 = *voxel;
+                }
+                if voxel[axis] > row.1[axis] {
+                    row.1 = *voxel;
+                }
+            }
+            for (first, last) in rows.into_values() {
+                extremes.insert(first);
+                extremes.insert(last);
+            }
+        }
+
+        let mut corners: HashSet<[usize; 3]> = HashSet::new();
+        for voxel in extremes {
+            for corner in 0..8 {
+                corners.insert([0, 1, 2].map(|axis| voxel[axis] + (corner >> axis & 1)));
+            }
+        }
+
+        corners
+            .into_iter()
+            .map(|corner| {
+                self.origin + DVec3::from_array(corner.map(|c| c as f64)) * self.voxel_size
+            })
+            .collect()
+    }
+
+    /// Splits the part in two along the axis-aligned plane that leaves the least concavity,
+    /// or returns `None` if the part cannot be split.
+    fn split(&self, part: &Part, mesh_volume: f64) -> Option<[Part; 2]> {
+        let mut best: Option<(f64, [Part; 2])> = None;
+
+        for axis in 0..3 {
+            let min = part.voxels.iter().map(|v| v[axis]).min()?;
+            let max = part.voxels.iter().map(|v| v[axis]).max()?;
+            if min == max {
+                continue;
+            }
+
+            // The voxels before the cut go to the first part.
+            let num_cuts = CUTS_PER_AXIS.min(max - min);
+            for n in 1..=num_cuts {
+                let cut = min + (n * (max - min + 1)) / (num_cuts + 1);
+                if cut <= min || cut > max {
+                    continue;
+                }
+
+                let (before, after): (Vec<_>, Vec<_>) =
+                    part.voxels.iter().partition(|v| v[axis] < cut);
+                let (Some(before), Some(after)) = (
+                    self.part(before, mesh_volume),
+                    self.part(after, mesh_volume),
+                ) else {
+                    continue;
+                };
+
+                let concavity = before.concavity + after.concavity;
+                if best.as_ref().is_none_or(|(best, _)| concavity < *best) {
+                    best = Some((concavity, [before, after]));
+                }
+            }
+        }
+
+        best.map(|(_, parts)| parts)
+    }
+}
+
+/// Computes where the ray along the x-axis through `y` and `z` crosses the triangle, if it does.
+fn ray_crossing(a: DVec3, b: DVec3, c: DVec3, y: f64, z: f64) -> Option<f64> {
+    // The barycentric coordinates of the ray in the triangle projected onto the yz-plane.
+    let det = (b.y - a.y) * (c.z - a.z) - (c.y - a.y) * (b.z - a.z);
+    if det == 0.0 {
+        return None;
+    }
+    let u = ((y - a.y) * (c.z - a.z) - (c.y - a.y) * (z - a.z)) / det;
+    let v = ((b.y - a.y) * (z - a.z) - (y - a.y) * (b.z - a.z)) / det;
+    if u < 0.0 || v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    Some(a.x + u * (b.x - a.x) + v * (c.x - a.x))
+}
@@ -0,0 +1,189 @@
+//! Delaunay triangulations from convex hulls of points lifted onto a paraboloid.
+
+use glam::{DVec2, DVec3};
+
+use crate::polygon::convex_hull_2d;
+use crate::simplicial::{Orientation, SimplicialHull};
+use crate::{ConvexHull, DegenerateInput, ErrorKind, ToRobust};
+
+/// Points lifted onto the paraboloid where the extra coordinate is the squared length of the point,
+/// along with a point infinitely far above the paraboloid.
+///
+/// The lifted coordinates are never computed, as the orientation of lifted points
+/// is the same as the in-circle or in-sphere test of the original points.
+struct LiftedPoints<'a, P> {
+    points: &'a [P],
+}
+
+impl<P> LiftedPoints<'_, P> {
+    /// The index of the point at infinity.
+    fn infinity(&self) -> usize {
+        self.points.len()
+    }
+}
+
+impl Orientation for LiftedPoints<'_, DVec2> {
+    type Plane = ();
+
+    fn dimension(&self) -> usize {
+        3
+    }
+
+    fn plane(&self, _facet: &[usize]) {}
+
+    fn orient(&self, facet: &[usize], _plane: &(), point: usize) -> f64 {
+        let rows = [facet[0], facet[1], facet[2], point];
+        let coord = |i: usize| robust::Coord {
+            x: self.points[i].x,
+            y: self.points[i].y,
+        };
+
+        match rows.iter().position(|i| *i == self.infinity()) {
+            // The homogeneous coordinates of the point at infinity are zero except for the lifted one,
+            // so the determinant reduces to the orientation of the remaining points in 2D.
+            Some(k) => {
+                let mut rest = rows.iter().filter(|i| **i != self.infinity());
+                let [a, b, c] = [(); 3].map(|_| coord(*rest.next().unwrap()));
+                let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+                sign * robust::orient2d(a, b, c)
+            }
+            None => {
+                let [a, b, c, d] = rows.map(coord);
+                robust::incircle(a, b, c, d)
+            }
+        }
+    }
+}
+
+impl Orientation for LiftedPoints<'_, DVec3> {
+    type Plane = ();
+
+    fn dimension(&self) -> usize {
+        4
+    }
+
+    fn plane(&self, _facet: &[usize]) {}
+
+    fn orient(&self, facet: &[usize], _plane: &(), point: usize) -> f64 {
+        let rows = [facet[0], facet[1], facet[2], facet[3], point];
+        let coord = |i: usize| self.points[i].to_robust();
+
+        match rows.iter().position(|i| *i == self.infinity()) {
+            // The homogeneous coordinates of the point at infinity are zero except for the lifted one,
+            // so the determinant reduces to the orientation of the remaining points in 3D.
+            Some(k) => {
+                let mut rest = rows.iter().filter(|i| **i != self.infinity());
+                let [a, b, c, d] = [(); 4].map(|_| coord(*rest.next().unwrap()));
+                let sign = if k % 2 == 0 { -1.0 } else { 1.0 };
+                sign * robust::orient3d(a, b, c, d)
+            }
+            None => {
+                let [a, b, c, d, e] = rows.map(coord);
+                robust::insphere(a, b, c, d, e)
+            }
+        }
+    }
+}
+
+/// Computes the Delaunay tetrahedralization of the given points.
+///
+/// The points are lifted onto the paraboloid `w = x² + y² + z²` in 4D, and each facet on the lower side
+/// of the convex hull of the lifted points is a tetrahedron whose circumsphere contains no other point.
+/// The lifted points are never rounded, as their orientation tests are evaluated as exact in-sphere tests.
+///
+/// The tetrahedra are returned as indices into `points`, ordered so that each has a positive volume,
+/// which means that `(b - a).cross(c - a).dot(d - a) > 0.0` for the points `[a, b, c, d]`.
+/// Duplicate points are only used once.
+///
+/// ## Errors
+/// If there are less than four points, or if the points are coincident, collinear or coplanar.
+pub fn delaunay_3d(points: &[DVec3]) -> Result<Vec<[usize; 4]>, ErrorKind> {
+    if points.is_empty() {
+        return Err(ErrorKind::Empty);
+    }
+
+    if points.len() <= 3 {
+        return Err(ErrorKind::Degenerated);
+    }
+
+    let (min_indices, max_indices) = ConvexHull::compute_extremes(points);
+    let [a, b, c, d] = ConvexHull::init_tetrahedron_indices(points, min_indices, max_indices)?;
+    let [pa, pb, pc, pd] = [a, b, c, d].map(|i| points[i].to_robust());
+    if robust::orient3d(pa, pb, pc, pd) == 0.0 {
+        return Err(ErrorKind::DegenerateInput(DegenerateInput::Coplanar));
+    }
+
+    // The point at infinity turns the lower hull of the lifted points into a closed convex hull,
+    // where the facets containing it stand on the boundary of the convex hull of the points.
+    let lifted = LiftedPoints { points };
+    let simplex = [a, b, c, d, lifted.infinity()];
+    let hull = SimplicialHull::try_new(&lifted, &simplex, 0..points.len())?;
+
+    let mut tetrahedra = Vec::new();
+    for facet in hull.facets() {
+        if facet.vertices.contains(&lifted.infinity()) {
+            continue;
+        }
+
+        // The point at infinity is behind every lower facet, so the facets have a negative volume.
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| facet.vertices[i]);
+        let [pa, pb, pc, pd] = [a, b, c, d].map(|i| points[i].to_robust());
+        if robust::orient3d(pa, pb, pc, pd) > 0.0 {
+            tetrahedra.push([b, a, c, d]);
+        }
+    }
+
+    Ok(tetrahedra)
+}
+
+/// Computes the Delaunay triangulation of the given points in the plane.
+///
+/// The points are lifted onto the paraboloid `z = x² + y²`, and each face on the lower side of
+/// the convex hull of the lifted points is a triangle whose circumcircle contains no other point.
+/// The lifted points are never rounded, as their orientation tests are evaluated as exact in-circle tests.
+/// This makes the triangulation robust for points on a common circle, such as the points of a grid,
+/// where any of the possible triangulations of the points on the circle is chosen.
+///
+/// The triangles are returned as indices into `points` in counterclockwise order.
+/// Duplicate points are only used once.
+///
+/// ## Errors
+/// If there are less than three points, or if the points are coincident or collinear.
+pub fn delaunay_2d(points: &[DVec2]) -> Result<Vec<[usize; 3]>, ErrorKind> {
+    if points.is_empty() {
+        return Err(ErrorKind::Empty);
+    }
+
+    if points.len() <= 2 {
+        return Err(ErrorKind::Degenerated);
+    }
+
+    // Any three vertices of the convex hull of the points form a triangle with a nonzero area.
+    let simplex = match convex_hull_2d(points)[..] {
+        [a, b, c, ..] => [a, b, c, points.len()],
+        [_, _] => return Err(ErrorKind::DegenerateInput(DegenerateInput::Collinear)),
+        _ => return Err(ErrorKind::DegenerateInput(DegenerateInput::Coincident)),
+    };
+
+    let lifted = LiftedPoints { points };
+    let hull = SimplicialHull::try_new(&lifted, &simplex, 0..points.len())?;
+
+    let mut triangles = Vec::new();
+    for facet in hull.facets() {
+        if facet.vertices.contains(&lifted.infinity()) {
+            continue;
+        }
+
+        // The point at infinity is behind every lower facet, so the facets are counterclockwise.
+        let [a, b, c] = [0, 1, 2].map(|i| facet.vertices[i]);
+        let [pa, pb, pc] = [a, b, c].map(|i| robust::Coord {
+            x: points[i].x,
+            y: points[i].y,
+        });
+        if robust::orient2d(pa, pb, pc) > 0.0 {
+            triangles.push([a, b, c]);
+        }
+    }
+
+    Ok(triangles)
+}
@@ -0,0 +1,122 @@
+//! Exact determinants with floating-point expansions.
+//!
+//! An expansion is a sum of floating-point numbers ordered by increasing magnitude, where no two
+//! components overlap, so the sign of the sum is the sign of the last component.
+//! See J. R. Shewchuk. 1997. [Adaptive Precision Floating-Point Arithmetic and Fast Robust
+//! Geometric Predicates](https://people.eecs.berkeley.edu/~jrs/papers/robustr.pdf).
+
+/// Computes `a + b` as the rounded sum and its round-off error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Computes `a + b` as the rounded sum and its round-off error, if `|a| >= |b|`.
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    (sum, b - (sum - a))
+}
+
+/// Computes `a * b` as the rounded product and its round-off error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+/// Adds `b` to the expansion `e`.
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut sum = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for component in e {
+        let (q_next, h) = two_sum(q, *component);
+        q = q_next;
+        if h != 0.0 {
+            sum.push(h);
+        }
+    }
+    if q != 0.0 {
+        sum.push(q);
+    }
+    sum
+}
+
+/// Adds the expansions `e` and `f`.
+pub(crate) fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let (mut sum, rest) = if e.len() >= f.len() {
+        (e.to_vec(), f)
+    } else {
+        (f.to_vec(), e)
+    };
+    for component in rest {
+        sum = grow_expansion(&sum, *component);
+    }
+    sum
+}
+
+/// Multiplies the expansion `e` by `b`.
+pub(crate) fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut product = Vec::with_capacity(2 * e.len());
+    let Some((first, rest)) = e.split_first() else {
+        return product;
+    };
+
+    let (mut q, h) = two_product(*first, b);
+    if h != 0.0 {
+        product.push(h);
+    }
+    for component in rest {
+        let (t_high, t_low) = two_product(*component, b);
+        let (sum, h) = two_sum(q, t_low);
+        if h != 0.0 {
+            product.push(h);
+        }
+        let (q_next, h) = fast_two_sum(t_high, sum);
+        q = q_next;
+        if h != 0.0 {
+            product.push(h);
+        }
+    }
+    if q != 0.0 {
+        product.push(q);
+    }
+    product
+}
+
+/// Computes the minors of a matrix with one more column than rows,
+/// leaving out each of the columns in turn, by Laplace expansion along the rows.
+///
+/// Each minor of the lower rows is shared by the minors of the rows above it, so it is only computed once.
+/// `accumulate(sum, entry, minor, negative)` adds the product of the `entry` and the `minor` to the `sum`,
+/// negating it if `negative` is `true`.
+pub(crate) fn laplace_minors<T: Clone>(
+    rows: &[&[f64]],
+    one: T,
+    zero: T,
+    accumulate: impl Fn(T, f64, &T, bool) -> T,
+) -> Vec<T> {
+    let columns = rows.len() + 1;
+    let all = (1usize << columns) - 1;
+
+    // The minor of the lowest rows and the columns in each bit set.
+    let mut minors: Vec<Option<T>> = vec![None; all + 1];
+    minors[0] = Some(one);
+
+    for size in 1..columns {
+        let row = rows[rows.len() - size];
+        for mask in (1..all).filter(|mask| mask.count_ones() as usize == size) {
+            let mut sum = zero.clone();
+            let set_columns = (0..columns).filter(|column| mask & (1 << column) != 0);
+            for (position, column) in set_columns.enumerate() {
+                let minor = minors[mask & !(1 << column)].as_ref().unwrap();
+                sum = accumulate(sum, row[column], minor, position % 2 == 1);
+            }
+            minors[mask] = Some(sum);
+        }
+    }
+
+    (0..columns)
+        .map(|column| minors[all & !(1 << column)].clone().unwrap())
+        .collect()
+}
@@ -0,0 +1,96 @@
+//! Convex hulls bounded by half-spaces.
+
+use glam::{DMat3, DVec3};
+
+use crate::{ConvexHull, ErrorKind, ToRobust};
+
+/// The relative tolerance for a vertex of the intersection to be considered inside a half-space.
+const HALF_SPACE_TOLERANCE: f64 = 1e-9;
+
+impl ConvexHull {
+    /// Computes the [`ConvexHull`] of the intersection of the given half-spaces.
+    ///
+    /// Each half-space `(normal, offset)` contains the points `x` for which `normal.dot(x) <= offset`,
+    /// so the normal points out of the resulting volume. The `interior_point` must be strictly inside
+    /// every half-space.
+    ///
+    /// Around the interior point, each half-space is dual to the point `normal / distance`,
+    /// where `distance` is how far the plane is in front of the interior point. The faces
+    /// of the convex hull of these dual points are in turn dual to the vertices of the intersection.
+    /// Redundant half-spaces are dual to points inside the dual convex hull, and are thus ignored.
+    ///
+    /// ## Errors
+    /// If the interior point is not strictly inside every half-space, [`ErrorKind::EmptyIntersection`]
+    /// is returned, and if the half-spaces do not bound a finite volume, [`ErrorKind::Unbounded`].
+    /// Otherwise, if computing either of the convex hulls fails, or round-off errors put a vertex
+    /// of the intersection outside of a half-space, [`ErrorKind::RoundOffError`] is returned.
+    pub fn from_half_spaces(
+        half_spaces: &[(DVec3, f64)],
+        interior_point: DVec3,
+    ) -> Result<Self, ErrorKind> {
+        let mut dual_points = Vec::with_capacity(half_spaces.len());
+        for (normal, offset) in half_spaces {
+            let distance = offset - normal.dot(interior_point);
+            if distance.is_nan() || distance <= 0.0 {
+                return Err(ErrorKind::EmptyIntersection);
+            }
+            dual_points.push(*normal / distance);
+        }
+
+        // The dual points are retained, so that the vertices of each dual face index the half-spaces.
+        let dual = match ConvexHull::try_new_retained(&dual_points, None) {
+            Ok(dual) => dual,
+            // Dual points that are too few or on a common plane cannot enclose the origin.
+            Err(ErrorKind::Empty | ErrorKind::Degenerated | ErrorKind::DegenerateInput(_)) => {
+                return Err(ErrorKind::Unbounded)
+            }
+            Err(err) => return Err(err),
+        };
+
+        let mut vertices = Vec::with_capacity(dual.faces.len());
+        for face in dual.faces.values() {
+            let [p0, p1, p2] = [0, 1, 2].map(|i| dual.points[face.indices[i]]);
+
+            // The intersection is only bounded if the origin is strictly inside the dual convex hull.
+            let position = -robust::orient3d(
+                p0.to_robust(),
+                p1.to_robust(),
+                p2.to_robust(),
+                DVec3::ZERO.to_robust(),
+            );
+            if position >= 0.0 {
+                return Err(ErrorKind::Unbounded);
+            }
+
+            // The face lies on the plane `normal.dot(y) == offset`, which is dual to the vertex
+            // `normal / offset` relative to the interior point.
+            let offset = face.normal.dot(p0 + p1 + p2) / 3.0;
+            let vertex = interior_point + face.normal / offset;
+
+            // Thin faces of the dual convex hull, from nearly equal half-spaces, have inaccurate normals.
+            // Their vertices end up outside of the other half-spaces, unlike accurate vertices.
+            let is_inside = half_spaces.iter().all(|(normal, offset)| {
+                let tolerance =
+                    HALF_SPACE_TOLERANCE * (offset.abs() + normal.length() * vertex.length());
+                normal.dot(vertex) <= offset + tolerance
+            });
+            if is_inside {
+                vertices.push(vertex);
+                continue;
+            }
+
+            // Only the faces between half-spaces whose planes nearly share a line can be skipped,
+            // as those planes meet in no single vertex. Skipping any other face would lose a vertex.
+            let normals = [0, 1, 2].map(|i| half_spaces[face.indices[i]].0);
+            let determinant = DMat3::from_cols(normals[0], normals[1], normals[2]).determinant();
+            let scale = normals.iter().map(|n| n.length()).product::<f64>();
+            if determinant.abs() > HALF_SPACE_TOLERANCE * scale {
+                return Err(ErrorKind::RoundOffError(
+                    "vertex of the intersection outside of a half-space",
+                ));
+            }
+        }
+
+        ConvexHull::try_new(&vertices, None)
+    }
+}
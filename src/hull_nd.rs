@@ -0,0 +1,343 @@
+//! Convex hulls in any dimension.
+
+use crate::exact::{expansion_sum, laplace_minors, scale_expansion};
+use crate::simplicial::{Orientation, SimplicialHull};
+use crate::{DegenerateInput, ErrorKind};
+
+/// A convex hull in `D` dimensions, representing the smallest convex set containing
+/// all input points in a given point set.
+///
+/// Unlike [`ConvexHull`](crate::ConvexHull), which is specialized for 3D, the dimension can be anything
+/// from two upwards, and the facets are always simplices with `D` points each. Facets on a common
+/// hyperplane, for example on the sides of a hypercube, are not merged.
+///
+/// Whether a point is in front of a facet is decided exactly, so the result is not affected
+/// by round-off errors. The orientation tests use determinants whose cost grows exponentially
+/// with the dimension, so this is intended for low dimensions.
+#[derive(Clone, Debug)]
+pub struct ConvexHullNd<const D: usize> {
+    /// The points of the convex hull, in the same order as the input points.
+    pub points: Vec<[f64; D]>,
+    /// The indices of the points of each facet.
+    facets: Vec<[usize; D]>,
+    /// The hyperplane through each facet.
+    planes: Vec<Hyperplane>,
+}
+
+impl<const D: usize> ConvexHullNd<D> {
+    /// Attempts to compute a [`ConvexHullNd`] for the given set of points.
+    ///
+    /// All points are kept, so the indices of the facets refer to the input points.
+    /// Points that are not vertices of the convex hull are simply not used by any facet.
+    ///
+    /// ## Errors
+    /// If there are no points, or if there are not more points than the dimension, or if the dimension
+    /// is less than two. If the points are in a common subspace of a lower dimension, they are reported
+    /// as [coincident](DegenerateInput::Coincident), [collinear](DegenerateInput::Collinear),
+    /// or otherwise [coplanar](DegenerateInput::Coplanar).
+    pub fn try_new(points: &[[f64; D]]) -> Result<Self, ErrorKind> {
+        if points.is_empty() {
+            return Err(ErrorKind::Empty);
+        }
+
+        if D < 2 || points.len() <= D {
+            return Err(ErrorKind::Degenerated);
+        }
+
+        let oracle = Points { points };
+        let simplex = initial_simplex(points)?;
+        let plane = oracle.plane(&simplex[..D]);
+        if oracle.orient(&simplex[..D], &plane, simplex[D]) == 0.0 {
+            return Err(ErrorKind::DegenerateInput(DegenerateInput::Coplanar));
+        }
+
+        let hull = SimplicialHull::try_new(&oracle, &simplex, 0..points.len())?;
+
+        let (facets, planes) = hull
+            .facets()
+            .map(|facet| {
+                let vertices: [usize; D] = facet.vertices[..].try_into().unwrap();
+                (vertices, oracle.plane(&vertices))
+            })
+            .unzip();
+
+        Ok(Self {
+            points: points.to_vec(),
+            facets,
+            planes,
+        })
+    }
+
+    /// Returns the facets of the convex hull, each as the indices of its points.
+    ///
+    /// The points are ordered so that the determinant of the homogeneous coordinates of a facet's
+    /// points, followed by a point outside of the convex hull, is positive.
+    #[must_use]
+    pub fn facets(&self) -> &[[usize; D]] {
+        &self.facets
+    }
+
+    /// Returns the indices of the points that are vertices of at least one facet, in increasing order.
+    #[must_use]
+    pub fn vertex_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self.facets.iter().flatten().copied().collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    /// Returns `true` if the point is inside of the convex hull or on its boundary.
+    #[must_use]
+    pub fn contains(&self, point: [f64; D]) -> bool {
+        self.facets
+            .iter()
+            .zip(&self.planes)
+            .all(|(facet, plane)| plane.orient(&self.points, facet, &point) <= 0.0)
+    }
+
+    /// Computes the volume of the convex hull.
+    ///
+    /// Sums up the volumes of the simplices from the centroid of the vertices to each facet.
+    #[must_use]
+    pub fn volume(&self) -> f64 {
+        let vertices = self.vertex_indices();
+        let mut centroid = [0.0; D];
+        for i in &vertices {
+            for (sum, x) in centroid.iter_mut().zip(self.points[*i]) {
+                *sum += x / vertices.len() as f64;
+            }
+        }
+
+        // The orientation determinant is the volume of the simplex times the factorial of the dimension.
+        let factorial: f64 = (1..=D).map(|i| i as f64).product();
+        let volume: f64 = self
+            .planes
+            .iter()
+            .map(|plane| plane.approximate(&centroid).abs())
+            .sum();
+        volume / factorial
+    }
+}
+
+/// The relative round-off error of an orientation determinant in `D` dimensions, computed by Laplace
+/// expansion, compared to the permanent of its absolute values.
+///
+/// Each term of the expansion passes through at most `(D + 1)(D + 2) / 2` rounded products and sums,
+/// one product and up to `k - 1` sums for the `k`-th row from the bottom, and the bound itself is rounded
+/// once more. Each rounding has a relative error of at most half the machine epsilon, so counting the full
+/// epsilon per rounding leaves room for the higher-order terms.
+fn error_bound_factor<const D: usize>() -> f64 {
+    ((D + 1) * (D + 2) / 2 + 1) as f64 * f64::EPSILON
+}
+
+/// The hyperplane through the points of a facet, as the cofactors of the orientation determinant
+/// along the row of the tested point.
+#[derive(Clone, Debug)]
+struct Hyperplane {
+    /// The rounded cofactors.
+    cofactors: Vec<f64>,
+    /// The permanents of the absolute values of the minors of the cofactors,
+    /// which bound the round-off errors of the cofactors.
+    permanents: Vec<f64>,
+}
+
+impl Hyperplane {
+    /// Computes the hyperplane through the points of the `facet`.
+    fn new<const D: usize>(points: &[[f64; D]], facet: &[usize]) -> Self {
+        let rows = homogeneous_rows(points, facet);
+        let rows: Vec<&[f64]> = rows.iter().map(|row| &row[..]).collect();
+        let minors = laplace_minors(
+            &rows,
+            (1.0, 1.0),
+            (0.0, 0.0),
+            |sum, entry, minor, negative| {
+                let term = entry * minor.0;
+                (
+                    if negative { sum.0 - term } else { sum.0 + term },
+                    sum.1 + entry.abs() * minor.1,
+                )
+            },
+        );
+
+        let (cofactors, permanents) = minors
+            .into_iter()
+            .enumerate()
+            .map(|(j, (minor, permanent))| (cofactor_sign::<D>(j) * minor, permanent))
+            .unzip();
+
+        Self {
+            cofactors,
+            permanents,
+        }
+    }
+
+    /// Computes the rounded orientation of the `point` relative to the hyperplane.
+    fn approximate(&self, point: &[f64]) -> f64 {
+        let last = self.cofactors.len() - 1;
+        let dot: f64 = point
+            .iter()
+            .zip(&self.cofactors)
+            .map(|(x, cofactor)| x * cofactor)
+            .sum();
+        dot + self.cofactors[last]
+    }
+
+    /// Computes the orientation of the `point` relative to the hyperplane through the points
+    /// of the `facet`, with an exact sign.
+    ///
+    /// The rounded orientation is used if it is larger than its error bound. Otherwise, the orientation
+    /// is recomputed exactly from the points.
+    fn orient<const D: usize>(
+        &self,
+        points: &[[f64; D]],
+        facet: &[usize],
+        point: &[f64; D],
+    ) -> f64 {
+        let approximate = self.approximate(point);
+        let bound: f64 = point
+            .iter()
+            .chain([&1.0])
+            .zip(&self.permanents)
+            .map(|(x, permanent)| x.abs() * permanent)
+            .sum();
+        if approximate.abs() > error_bound_factor::<D>() * bound {
+            return approximate;
+        }
+
+        // The points of the facet are common test points, such as when checking if a vertex is contained.
+        if facet.iter().any(|i| points[*i] == *point) {
+            return 0.0;
+        }
+
+        let rows = homogeneous_rows(points, facet);
+        let rows: Vec<&[f64]> = rows.iter().map(|row| &row[..]).collect();
+        let minors = laplace_minors(
+            &rows,
+            vec![1.0],
+            Vec::new(),
+            |sum, entry, minor, negative| {
+                let term = scale_expansion(minor, if negative { -entry } else { entry });
+                expansion_sum(&sum, &term)
+            },
+        );
+
+        let mut orientation = Vec::new();
+        for (j, (minor, x)) in minors.iter().zip(point.iter().chain([&1.0])).enumerate() {
+            let term = scale_expansion(minor, cofactor_sign::<D>(j) * x);
+            orientation = expansion_sum(&orientation, &term);
+        }
+        orientation.last().copied().unwrap_or(0.0)
+    }
+}
+
+/// The sign of the cofactor of the entry in the `column` of the last row of an orientation determinant.
+fn cofactor_sign<const D: usize>(column: usize) -> f64 {
+    if (D + column).is_multiple_of(2) {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// Returns the homogeneous coordinates of the points with the given indices.
+fn homogeneous_rows<const D: usize>(points: &[[f64; D]], indices: &[usize]) -> Vec<Vec<f64>> {
+    indices
+        .iter()
+        .map(|i| points[*i].iter().copied().chain([1.0]).collect())
+        .collect()
+}
+
+/// The points of a [`ConvexHullNd`].
+struct Points<'a, const D: usize> {
+    points: &'a [[f64; D]],
+}
+
+impl<const D: usize> Orientation for Points<'_, D> {
+    type Plane = Hyperplane;
+
+    fn dimension(&self) -> usize {
+        D
+    }
+
+    fn plane(&self, facet: &[usize]) -> Hyperplane {
+        Hyperplane::new(self.points, facet)
+    }
+
+    fn orient(&self, facet: &[usize], plane: &Hyperplane, point: usize) -> f64 {
+        plane.orient(self.points, facet, &self.points[point])
+    }
+}
+
+/// Computes the indices of the points of the initial simplex, starting with the two points
+/// that are farthest apart along an axis, and adding the point farthest from the affine subspace
+/// spanned by the previous points until there are `D + 1` points.
+fn initial_simplex<const D: usize>(points: &[[f64; D]]) -> Result<Vec<usize>, ErrorKind> {
+    let mut max_extent = 0.0;
+    let mut simplex = vec![0, 0];
+    for axis in 0..D {
+        let by_axis = |a: &&[f64; D], b: &&[f64; D]| a[axis].total_cmp(&b[axis]);
+        let (min, _) = points
+            .iter()
+            .enumerate()
+            .min_by(|a, b| by_axis(&a.1, &b.1))
+            .unwrap();
+        let (max, _) = points
+            .iter()
+            .enumerate()
+            .max_by(|a, b| by_axis(&a.1, &b.1))
+            .unwrap();
+
+        let extent = points[max][axis] - points[min][axis];
+        if extent > max_extent {
+            max_extent = extent;
+            simplex = vec![min, max];
+        }
+    }
+
+    if max_extent == 0.0 {
+        return Err(ErrorKind::DegenerateInput(DegenerateInput::Coincident));
+    }
+
+    // An orthonormal basis of the affine subspace spanned by the points of the simplex.
+    let origin = points[simplex[0]];
+    let offset =
+        |i: usize| -> Vec<f64> { points[i].iter().zip(origin).map(|(x, o)| x - o).collect() };
+    let normalize = |v: Vec<f64>| -> Vec<f64> {
+        let length = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        v.iter().map(|x| x / length).collect()
+    };
+    let mut basis = vec![normalize(offset(simplex[1]))];
+
+    while simplex.len() <= D {
+        let mut farthest = None;
+        let mut max_distance_squared = 0.0;
+        for i in 0..points.len() {
+            let mut residual = offset(i);
+            for axis in &basis {
+                let dot: f64 = residual.iter().zip(axis).map(|(r, a)| r * a).sum();
+                residual
+                    .iter_mut()
+                    .zip(axis)
+                    .for_each(|(r, a)| *r -= dot * a);
+            }
+
+            let distance_squared: f64 = residual.iter().map(|r| r * r).sum();
+            if distance_squared > max_distance_squared {
+                max_distance_squared = distance_squared;
+                farthest = Some((i, residual));
+            }
+        }
+
+        let Some((i, residual)) = farthest else {
+            return Err(ErrorKind::DegenerateInput(if simplex.len() == 2 {
+                DegenerateInput::Collinear
+            } else {
+                DegenerateInput::Coplanar
+            }));
+        };
+        simplex.push(i);
+        basis.push(normalize(residual));
+    }
+
+    Ok(simplex)
+}
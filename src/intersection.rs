@@ -0,0 +1,244 @@
+//! Intersections of convex hulls with each other and with planes.
+
+use glam::{DVec2, DVec3};
+
+use std::collections::BTreeMap;
+
+use crate::polygon::convex_hull_2d;
+use crate::{ConvexHull, ErrorKind, ToRobust};
+
+/// The relative distance within which points are considered to be on a splitting plane.
+///
+/// Snapping these points onto the plane keeps crossing points from being created right next to them.
+const PLANE_TOLERANCE: f64 = 1e-12;
+
+impl ConvexHull {
+    /// Computes the intersection of two convex hulls, the volume contained in both of them.
+    ///
+    /// Every vertex of the intersection is either a vertex of one hull inside the other,
+    /// or the point where an edge of one hull crosses a face of the other. These candidates are found
+    /// with exact orientation tests, so thin faces cannot cut into the result, and the convex hull
+    /// is only computed once for all candidates.
+    ///
+    /// Returns `None` if the convex hulls do not overlap, or if they only touch
+    /// so that the intersection has no volume.
+    ///
+    /// ## Errors
+    /// If computing the convex hull of the candidates fails for another reason than
+    /// the intersection having no volume, such as round-off errors.
+    pub fn intersection(a: &ConvexHull, b: &ConvexHull) -> Result<Option<Self>, ErrorKind> {
+        let mut candidates = Vec::new();
+
+        for (c_hull, other) in [(a, b), (b, a)] {
+            let triangles: Vec<[DVec3; 3]> = other
+                .faces()
+                .map(|(_, face)| [0, 1, 2].map(|i| other.points[face.indices[i]]))
+                .collect();
+
+            for i in c_hull.vertex_indices() {
+                let point = c_hull.points[i];
+                if triangles
+                    .iter()
+                    .all(|triangle| position_from_triangle(triangle, point) <= 0.0)
+                {
+                    candidates.push(point);
+                }
+            }
+
+            for ([i, j], _) in c_hull.edges() {
+                let (p, q) = (c_hull.points[i], c_hull.points[j]);
+                candidates.extend(
+                    triangles
+                        .iter()
+                        .filter_map(|triangle| segment_triangle_crossing(p, q, triangle)),
+                );
+            }
+        }
+
+        match ConvexHull::try_new(&candidates, None) {
+            Ok(c_hull) => Ok(Some(c_hull)),
+            Err(ErrorKind::Empty | ErrorKind::Degenerated | ErrorKind::DegenerateInput(_)) => {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Splits the convex hull with the plane `normal.dot(x) == offset` into the part behind the plane
+    /// and the part in front of it, each closed off by a cap face on the plane.
+    ///
+    /// The vertices of each part are the vertices of the convex hull on its side of the plane,
+    /// and the points where the edges of the convex hull cross the plane. The original vertices
+    /// are kept as they are, so splitting a convex hull repeatedly does not move them.
+    ///
+    /// A part is `None` if the convex hull has no volume on its side of the plane.
+    ///
+    /// ## Panics
+    /// If the normal is zero or not finite.
+    #[must_use]
+    pub fn split(&self, normal: DVec3, offset: f64) -> (Option<Self>, Option<Self>) {
+        assert_plane_normal(normal);
+        let distances = self.plane_distances(normal, offset);
+
+        let mut behind: Vec<DVec3> = Vec::new();
+        let mut in_front: Vec<DVec3> = Vec::new();
+        for (i, distance) in &distances {
+            if *distance <= 0.0 {
+                behind.push(self.points[*i]);
+            }
+            if *distance >= 0.0 {
+                in_front.push(self.points[*i]);
+            }
+        }
+
+        let crossings = self.plane_crossings(&distances);
+        behind.extend(&crossings);
+        in_front.extend(&crossings);
+
+        (
+            ConvexHull::try_new(&behind, None).ok(),
+            ConvexHull::try_new(&in_front, None).ok(),
+        )
+    }
+
+    /// Clips the convex hull with the plane `normal.dot(x) == offset`, keeping the part behind the plane.
+    ///
+    /// Returns `None` if the convex hull has no volume behind the plane. See [`ConvexHull::split`].
+    ///
+    /// ## Panics
+    /// If the normal is zero or not finite.
+    #[must_use]
+    pub fn clip(&self, normal: DVec3, offset: f64) -> Option<Self> {
+        self.split(normal, offset).0
+    }
+
+    /// Computes the convex polygon where the plane `normal.dot(x) == offset` meets the convex hull,
+    /// ordered counterclockwise around the normal.
+    ///
+    /// The polygon is made of the vertices on the plane and the points where the edges of the convex hull
+    /// cross the plane. If the plane only touches the convex hull, the polygon degenerates into
+    /// a single point or a segment, and if the plane misses the convex hull, it is empty.
+    ///
+    /// ## Panics
+    /// If the normal is zero or not finite.
+    #[must_use]
+    pub fn cross_section(&self, normal: DVec3, offset: f64) -> Vec<DVec3> {
+        assert_plane_normal(normal);
+        let distances = self.plane_distances(normal, offset);
+        let mut points = self.plane_crossings(&distances);
+        points.extend(
+            distances
+                .iter()
+                .filter(|(_, distance)| **distance == 0.0)
+                .map(|(i, _)| self.points[*i]),
+        );
+
+        // Order the points by their convex hull after dropping the coordinate along which
+        // the normal is largest, which keeps points on a common edge exactly in line.
+        let axis = normal.abs().max_position();
+        let projected: Vec<DVec2> = points
+            .iter()
+            .map(|p| {
+                let (u, v) = (p[(axis + 1) % 3], p[(axis + 2) % 3]);
+                if normal[axis] > 0.0 {
+                    DVec2::new(u, v)
+                } else {
+                    DVec2::new(v, u)
+                }
+            })
+            .collect();
+
+        convex_hull_2d(&projected)
+            .into_iter()
+            .map(|i| points[i])
+            .collect()
+    }
+
+    /// Computes the signed distance of each vertex from the plane `normal.dot(x) == offset`,
+    /// scaled by the length of the normal.
+    ///
+    /// Vertices within the plane tolerance have a distance of exactly zero.
+    fn plane_distances(&self, normal: DVec3, offset: f64) -> BTreeMap<usize, f64> {
+        let vertices = self.vertex_indices();
+        let scale = vertices
+            .iter()
+            .map(|i| self.points[*i].abs().max_element())
+            .fold(offset.abs() / normal.length(), f64::max);
+        let tolerance = PLANE_TOLERANCE * normal.length() * scale;
+
+        vertices
+            .into_iter()
+            .map(|i| {
+                let distance = normal.dot(self.points[i]) - offset;
+                (
+                    i,
+                    if distance.abs() > tolerance {
+                        distance
+                    } else {
+                        0.0
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Computes the points where the edges of the convex hull cross a plane,
+    /// given the distances of the vertices from it.
+    fn plane_crossings(&self, distances: &BTreeMap<usize, f64>) -> Vec<DVec3> {
+        self.edges()
+            .into_iter()
+            .filter_map(|([i, j], _)| {
+                let (d_p, d_q) = (distances[&i], distances[&j]);
+                ((d_p < 0.0 && d_q > 0.0) || (d_p > 0.0 && d_q < 0.0))
+                    .then(|| self.points[i].lerp(self.points[j], d_p / (d_p - d_q)))
+            })
+            .collect()
+    }
+}
+
+/// Asserts that the `normal` of a plane is nonzero and finite, as there is no plane otherwise.
+fn assert_plane_normal(normal: DVec3) {
+    assert!(
+        normal.is_finite() && normal != DVec3::ZERO,
+        "plane normal {normal} must be nonzero and finite"
+    );
+}
+
+/// The exact signed distance of the point from the plane of the triangle, scaled by twice its area.
+///
+/// Positive in front of the counterclockwise triangle.
+fn position_from_triangle([a, b, c]: &[DVec3; 3], point: DVec3) -> f64 {
+    -robust::orient3d(
+        a.to_robust(),
+        b.to_robust(),
+        c.to_robust(),
+        point.to_robust(),
+    )
+}
+
+/// Returns the point where the segment from `p` to `q` crosses the triangle, including its border.
+///
+/// Segments lying in the plane of the triangle do not cross it, the vertices of the intersection
+/// on them are found through the other faces.
+fn segment_triangle_crossing(p: DVec3, q: DVec3, triangle: &[DVec3; 3]) -> Option<DVec3> {
+    let d_p = position_from_triangle(triangle, p);
+    let d_q = position_from_triangle(triangle, q);
+    if d_p == d_q || (d_p > 0.0 && d_q > 0.0) || (d_p < 0.0 && d_q < 0.0) {
+        return None;
+    }
+
+    // The line through the segment passes through the triangle if it is on the same side
+    // of all three edges of the triangle.
+    let [a, b, c] = triangle.map(ToRobust::to_robust);
+    let (p_r, q_r) = (p.to_robust(), q.to_robust());
+    let sides = [
+        robust::orient3d(p_r, q_r, a, b),
+        robust::orient3d(p_r, q_r, b, c),
+        robust::orient3d(p_r, q_r, c, a),
+    ];
+    if sides.iter().any(|s| *s > 0.0) && sides.iter().any(|s| *s < 0.0) {
+        return None;
+    }
+
+    Some(p.lerp(q, d_p / (d_p - d_q)))
+}
@@ -14,10 +14,38 @@
 
 use glam::{DMat4, DVec3};
 
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
 
+mod antipodal;
+mod bounds;
+mod collision;
+mod decomposition;
+mod delaunay;
+mod exact;
+mod half_space;
+mod hull_nd;
+mod intersection;
+mod minkowski;
+mod offset;
+mod polygon;
+mod primitives;
+mod projection;
+mod simplicial;
+mod simplify;
+mod transform;
+mod voronoi;
+
+pub use antipodal::AntipodalPair;
+pub use collision::Contact;
+pub use decomposition::DecompositionParams;
+pub use delaunay::{delaunay_2d, delaunay_3d};
+pub use hull_nd::ConvexHullNd;
+pub use primitives::{Primitive, RoundedConvexHull};
+pub use projection::plane_basis;
+pub use voronoi::{voronoi_cells_2d, voronoi_cells_3d};
+
 #[cfg(test)]
 mod tests;
 
@@ -66,6 +94,13 @@ pub enum ErrorKind {
     DegenerateInput(DegenerateInput),
     /// A round-off error.
     RoundOffError(&'static str),
+    /// The given half-spaces do not bound a finite volume.
+    Unbounded,
+    /// The given interior point is not strictly inside every half-space,
+    /// so the intersection of the half-spaces may be empty.
+    EmptyIntersection,
+    /// The given transform is not an invertible affine transform.
+    InvalidTransform,
 }
 
 /// The type of degeneracy for when attempting to compute a convex hull for a point set.
@@ -88,11 +123,41 @@ impl fmt::Display for ErrorKind {
             ErrorKind::RoundOffError(msg) => {
                 write!(f, "erroneous results by roundoff error: {msg}")
             }
+            ErrorKind::Unbounded => write!(f, "unbounded"),
+            ErrorKind::EmptyIntersection => write!(f, "empty intersection"),
+            ErrorKind::InvalidTransform => write!(f, "invalid transform"),
         }
     }
 }
 impl Error for ErrorKind {}
 
+/// The position of a point relative to a [`ConvexHull`], as returned by [`ConvexHull::classify_points`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointKind {
+    /// The point is a vertex of the convex hull.
+    Vertex,
+    /// The point is strictly inside the convex hull.
+    Interior,
+    /// The point is not a vertex, but lies on or near the surface of the convex hull.
+    Coplanar,
+}
+
+/// The outcome of [`ConvexHull::insert_point`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertResult {
+    /// The point is inside or on the surface of the convex hull, which was left unchanged.
+    Inside,
+    /// The point was inserted as a new vertex of the convex hull.
+    Inserted {
+        /// The index of the new vertex in [`ConvexHull::points`].
+        index: usize,
+        /// The keys of the faces that were added.
+        added_faces: Vec<usize>,
+        /// The keys of the faces that were removed.
+        removed_faces: Vec<usize>,
+    },
+}
+
 /// A 3D convex hull representing the smallest convex set containing
 /// all input points in a given point set.
 ///
@@ -103,6 +168,8 @@ pub struct ConvexHull {
     pub points: Vec<DVec3>,
     /// The faces of the convex hull.
     faces: BTreeMap<usize, Face>,
+    /// Whether points that are not vertices of the convex hull are kept in [`ConvexHull::points`].
+    retain_points: bool,
 }
 
 impl ConvexHull {
@@ -128,18 +195,84 @@ impl ConvexHull {
         c_hull.update(max_iter)?;
 
         // Shrink the hull, removing unused points.
-        c_hull.remove_unused_points();
+        c_hull.finish_update()?;
+
+        Ok(c_hull)
+    }
+
+    /// Attempts to compute a [`ConvexHull`] for the given set of points, keeping every input point.
+    ///
+    /// Unlike [`ConvexHull::try_new`], points that are not vertices of the convex hull are not discarded,
+    /// neither here nor in later updates, so the indices of [`ConvexHull::points`] match the input.
+    /// Use [`ConvexHull::classify_points`] to tell the hull vertices apart from the interior points.
+    ///
+    /// ## Errors
+    /// If their are 0 or <= 3 points.
+    pub fn try_new_retained(points: &[DVec3], max_iter: Option<usize>) -> Result<Self, ErrorKind> {
+        if points.is_empty() {
+            return Err(ErrorKind::Empty);
+        }
 
-        if c_hull.points.len() <= 3 {
+        if points.len() <= 3 {
             return Err(ErrorKind::Degenerated);
         }
 
+        let mut c_hull = Self::init_tetrahedron(points)?;
+        c_hull.retain_points = true;
+        c_hull.update(max_iter)?;
+        c_hull.finish_update()?;
+
+        Ok(c_hull)
+    }
+
+    /// Returns `true` if points that are not vertices of the convex hull are kept
+    /// in [`ConvexHull::points`].
+    #[must_use]
+    pub fn retains_points(&self) -> bool {
+        self.retain_points
+    }
+
+    /// Computes the [`ConvexHull`] of the union of the given convex hulls.
+    ///
+    /// The hull with the most vertices is used as the starting point, and the vertices of the other hulls
+    /// are added to it. Only the points outside the starting hull are processed by the quickhull algorithm,
+    /// which is faster than computing the convex hull of all vertices from scratch.
+    ///
+    /// Interior points are not carried over, even if they are [retained](ConvexHull::try_new_retained).
+    ///
+    /// ## Errors
+    /// If no convex hulls are given, or if updating the convex hull fails.
+    pub fn merge(hulls: &[&ConvexHull]) -> Result<Self, ErrorKind> {
+        let Some(seed) = hulls
+            .iter()
+            .max_by_key(|c_hull| c_hull.vertex_indices().len())
+        else {
+            return Err(ErrorKind::Empty);
+        };
+
+        let mut c_hull = (*seed).clone();
+        c_hull.retain_points = false;
+        c_hull.remove_unused_points();
+        for face in c_hull.faces.values_mut() {
+            face.outside_points.clear();
+        }
+
+        for other in hulls.iter().filter(|other| !std::ptr::eq(**other, *seed)) {
+            let vertices = other.vertex_indices();
+            c_hull
+                .points
+                .extend(vertices.into_iter().map(|i| other.points[i]));
+        }
+
+        c_hull.update(None)?;
+        c_hull.finish_update()?;
+
         Ok(c_hull)
     }
 
     /// Computes the minimum and maximum extents for the given point set, along with
     /// the indices of the minimum and maximum vertices along each coordinate axis.
-    fn compute_extremes(points: &[DVec3]) -> ([usize; 3], [usize; 3]) {
+    pub(crate) fn compute_extremes(points: &[DVec3]) -> ([usize; 3], [usize; 3]) {
         let mut min = points[0];
         let mut max = points[0];
 
@@ -225,6 +358,7 @@ impl ConvexHull {
         let simplex = Self {
             points: points.to_vec(),
             faces,
+            retain_points: false,
         };
 
         Ok(simplex)
@@ -386,109 +520,12 @@ impl ConvexHull {
             let visible_set =
                 initialize_visible_set(&self.points, furthest_point_index, &self.faces, key, face);
 
-            // Get the horizon.
-            let horizon = compute_horizon(&visible_set, &self.faces)?;
-
-            // Create new faces connecting the horizon vertices to the furthest point.
-            let mut new_keys = Vec::with_capacity(horizon.len());
-            for (ridge, unvisible) in horizon {
-                let mut new_face = vec![furthest_point_index];
-
-                assigned_point_indices.insert(furthest_point_index);
-
-                for point in ridge {
-                    new_face.push(point);
-                    assigned_point_indices.insert(point);
-                }
-
-                if new_face.len() != 3 {
-                    return Err(ErrorKind::RoundOffError(
-                        "number of new face's vertices should be 3",
-                    ));
-                }
-
-                let mut new_face = Face::from_triangle(&self.points, new_face.try_into().unwrap());
-                new_face.neighbor_faces.push(unvisible);
-
-                let new_key = face_add_count;
-                face_add_count += 1;
-
-                self.faces.insert(new_key, new_face);
-                let unvisible_faset = self.faces.get_mut(&unvisible).unwrap();
-                unvisible_faset.neighbor_faces.push(new_key);
-                new_keys.push(new_key);
-            }
-
-            if new_keys.len() < 3 {
-                return Err(ErrorKind::RoundOffError(
-                    "number of new faces should be grater than 3",
-                ));
-            }
-
-            // Link the faces to their neighbors.
-            for (i, key_a) in new_keys.iter().enumerate() {
-                let points_of_new_face_a: HashSet<_> = self
-                    .faces.get(key_a)
-                    .unwrap().indices
-                    .iter().copied()
-                    .collect();
-
-                for key_b in new_keys.iter().skip(i + 1) {
-                    let points_of_new_face_b: HashSet<_> = self
-                        .faces
-                        .get(key_b).unwrap()
-                        .indices.iter()
-                        .copied().collect();
-
-                    let num_intersection_points = points_of_new_face_a
-                        .intersection(&points_of_new_face_b)
-                        .collect::<Vec<_>>()
-                        .len();
-
-                    if num_intersection_points == 2 {
-                        let face_a = self.faces.get_mut(key_a).unwrap();
-                        face_a.neighbor_faces.push(*key_b);
-
-                        let face_b = self.faces.get_mut(key_b).unwrap();
-                        face_b.neighbor_faces.push(*key_a);
-                    }
-                }
-
-                let face_a = self.faces.get(key_a).unwrap();
-                if face_a.neighbor_faces.len() != 3 {
-                    return Err(ErrorKind::RoundOffError(
-                        "number of neighbors should be 3",
-                    ));
-                }
-            }
-
-            // Check the order of the new face's vertices.
-            for new_key in &new_keys {
-                let new_face = self.faces.get(new_key).unwrap();
-                let mut degenerate = true;
-
-                for assigned_point_index in &assigned_point_indices {
-                    let position = position_from_face(&self.points, new_face, *assigned_point_index);
-
-                    if position == 0.0 {
-                        continue;
-                    } else if position > 0.0 {
-                        let new_face = self.faces.get_mut(new_key).unwrap();
-                        new_face.indices.swap(0, 1);
-                        new_face.normal = -new_face.normal;
-                        new_face.distance_from_origin = -new_face.distance_from_origin;
-                        degenerate = false;
-                        break;
-                    }
-
-                    degenerate = false;
-                    break;
-                }
-
-                if degenerate {
-                    return Err(ErrorKind::Degenerated);
-                }
-            }
+            let new_keys = self.add_horizon_faces(
+                furthest_point_index,
+                &visible_set,
+                &mut assigned_point_indices,
+                &mut face_add_count,
+            )?;
 
             // Assign the orphaned vertices to the new faces.
             let mut visible_faces = Vec::with_capacity(visible_set.len());
@@ -522,22 +559,7 @@ impl ConvexHull {
                     .sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
             }
 
-            // Delete the old visible faces.
-            for visible in visible_set {
-                let visible_face = self.faces.get(&visible).unwrap().clone();
-                for neighbor_key in visible_face.neighbor_faces {
-                    let neighbor = self.faces.get_mut(&neighbor_key).unwrap();
-                    let index = neighbor
-                        .neighbor_faces
-                        .iter()
-                        .enumerate()
-                        .find(|(_, k)| **k == visible)
-                        .map(|(i, _)| i)
-                        .unwrap();
-                    neighbor.neighbor_faces.swap_remove(index);
-                }
-                self.faces.remove(&visible);
-            }
+            self.remove_faces(&visible_set);
         }
 
         if !self.is_convex() {
@@ -547,6 +569,148 @@ impl ConvexHull {
         Ok(())
     }
 
+    /// Creates faces connecting the horizon of the `visible_set` to the point at `eye_index`,
+    /// returning the keys of the new faces.
+    ///
+    /// The visible faces are left in place, so that their outside points can still be reassigned.
+    fn add_horizon_faces(
+        &mut self,
+        eye_index: usize,
+        visible_set: &HashSet<usize>,
+        assigned_point_indices: &mut HashSet<usize>,
+        face_add_count: &mut usize,
+    ) -> Result<Vec<usize>, ErrorKind> {
+        // Get the horizon.
+        let horizon = compute_horizon(visible_set, &self.faces)?;
+
+        // Create new faces connecting the horizon vertices to the eye point.
+        let mut new_keys = Vec::with_capacity(horizon.len());
+        for (ridge, unvisible) in horizon {
+            let mut new_face = vec![eye_index];
+
+            assigned_point_indices.insert(eye_index);
+
+            for point in ridge {
+                new_face.push(point);
+                assigned_point_indices.insert(point);
+            }
+
+            if new_face.len() != 3 {
+                return Err(ErrorKind::RoundOffError(
+                    "number of new face's vertices should be 3",
+                ));
+            }
+
+            let mut new_face = Face::from_triangle(&self.points, new_face.try_into().unwrap());
+            new_face.neighbor_faces.push(unvisible);
+
+            let new_key = *face_add_count;
+            *face_add_count += 1;
+
+            self.faces.insert(new_key, new_face);
+            let unvisible_faset = self.faces.get_mut(&unvisible).unwrap();
+            unvisible_faset.neighbor_faces.push(new_key);
+            new_keys.push(new_key);
+        }
+
+        if new_keys.len() < 3 {
+            return Err(ErrorKind::RoundOffError(
+                "number of new faces should be grater than 3",
+            ));
+        }
+
+        // Link the faces to their neighbors.
+        for (i, key_a) in new_keys.iter().enumerate() {
+            let points_of_new_face_a: HashSet<_> = self
+                .faces
+                .get(key_a)
+                .unwrap()
+                .indices
+                .iter()
+                .copied()
+                .collect();
+
+            for key_b in new_keys.iter().skip(i + 1) {
+                let points_of_new_face_b: HashSet<_> = self
+                    .faces
+                    .get(key_b)
+                    .unwrap()
+                    .indices
+                    .iter()
+                    .copied()
+                    .collect();
+
+                let num_intersection_points = points_of_new_face_a
+                    .intersection(&points_of_new_face_b)
+                    .collect::<Vec<_>>()
+                    .len();
+
+                if num_intersection_points == 2 {
+                    let face_a = self.faces.get_mut(key_a).unwrap();
+                    face_a.neighbor_faces.push(*key_b);
+
+                    let face_b = self.faces.get_mut(key_b).unwrap();
+                    face_b.neighbor_faces.push(*key_a);
+                }
+            }
+
+            let face_a = self.faces.get(key_a).unwrap();
+            if face_a.neighbor_faces.len() != 3 {
+                return Err(ErrorKind::RoundOffError("number of neighbors should be 3"));
+            }
+        }
+
+        // Check the order of the new face's vertices.
+        for new_key in &new_keys {
+            let new_face = self.faces.get(new_key).unwrap();
+            let mut degenerate = true;
+
+            for assigned_point_index in assigned_point_indices.iter() {
+                let position = position_from_face(&self.points, new_face, *assigned_point_index);
+
+                if position == 0.0 {
+                    continue;
+                } else if position > 0.0 {
+                    let new_face = self.faces.get_mut(new_key).unwrap();
+                    new_face.indices.swap(0, 1);
+                    new_face.normal = -new_face.normal;
+                    new_face.distance_from_origin = -new_face.distance_from_origin;
+                    degenerate = false;
+                    break;
+                }
+
+                degenerate = false;
+                break;
+            }
+
+            if degenerate {
+                return Err(ErrorKind::Degenerated);
+            }
+        }
+
+        Ok(new_keys)
+    }
+
+    /// Removes the faces in the `visible_set`, unlinking them from their neighbors.
+    fn remove_faces(&mut self, visible_set: &HashSet<usize>) {
+        // Delete the old visible faces.
+        for visible in visible_set.iter().copied() {
+            let visible_face = self.faces.get(&visible).unwrap().clone();
+            for neighbor_key in visible_face.neighbor_faces {
+                let neighbor = self.faces.get_mut(&neighbor_key).unwrap();
+                let index = neighbor
+                    .neighbor_faces
+                    .iter()
+                    .enumerate()
+                    .find(|(_, k)| **k == visible)
+                    .map(|(i, _)| i)
+                    .unwrap();
+                neighbor.neighbor_faces.swap_remove(index);
+            }
+            self.faces.remove(&visible);
+        }
+    }
+
     /// Adds the given points to the point set, attempting to update the convex hull.
     ///
     /// ## Errors
@@ -554,13 +718,7 @@ impl ConvexHull {
     pub fn add_points(&mut self, points: &mut Vec<DVec3>) -> Result<(), ErrorKind> {
         self.points.append(points);
         self.update(None)?;
-        self.remove_unused_points();
-
-        if self.points.len() <= 3 {
-            return Err(ErrorKind::Degenerated);
-        }
-
-        Ok(())
+        self.finish_update()
     }
 
     /// Adds the given iterator of points to the point set, attempting to update the convex hull.
@@ -570,15 +728,364 @@ impl ConvexHull {
     pub fn add_iter_points(&mut self, points: impl Iterator<Item = DVec3>) -> Result<(), ErrorKind> {
         self.points.extend(points);
         self.update(None)?;
-        self.remove_unused_points();
+        self.finish_update()
+    }
+
+    /// Inserts a single point, patching the convex hull locally around the faces the point can see.
+    ///
+    /// Unlike [`ConvexHull::add_points`], this does not recompute the outside points of every face,
+    /// so it only costs a scan for the first visible face followed by work proportional to
+    /// the visible region. If the point buries vertices, shifting the indices of the points
+    /// after them also takes a pass over the faces. The returned [`InsertResult`] lists the keys
+    /// of the faces that were added and removed, which can be used to update data derived
+    /// from [`ConvexHull::faces`].
+    ///
+    /// Points that stop being vertices are removed from [`ConvexHull::points`] unless
+    /// they are [retained](ConvexHull::try_new_retained), which can shift the indices of other points.
+    ///
+    /// On error, the convex hull is left unchanged.
+    ///
+    /// ## Errors
+    /// If patching the convex hull fails because of round-off errors or degeneracies.
+    pub fn insert_point(&mut self, point: DVec3) -> Result<InsertResult, ErrorKind> {
+        let eye_index = self.points.len();
+        self.points.push(point);
+
+        let Some((&key, face)) = self
+            .faces
+            .iter()
+            .find(|(_, face)| position_from_face(&self.points, face, eye_index) > 0.0)
+        else {
+            if !self.retain_points {
+                self.points.pop();
+            }
+            return Ok(InsertResult::Inside);
+        };
+
+        let visible_set = initialize_visible_set(&self.points, eye_index, &self.faces, key, face);
+
+        // The vertices of the visible faces are enough to orient the new faces, as the vertex
+        // of a visible face opposite to a horizon edge is behind the new face on that edge.
+        let visible_vertices: BTreeSet<usize> = visible_set
+            .iter()
+            .flat_map(|key| self.faces[key].indices.iter().copied())
+            .collect();
+        let mut assigned_point_indices: HashSet<usize> = visible_vertices.iter().copied().collect();
+
+        // Keep the faces across the horizon, so that a failed patch can be rolled back.
+        let hidden_neighbors: BTreeMap<usize, Face> = visible_set
+            .iter()
+            .flat_map(|key| self.faces[key].neighbor_faces.iter().copied())
+            .filter(|key| !visible_set.contains(key))
+            .map(|key| (key, self.faces[&key].clone()))
+            .collect();
+        let first_new_key = *self.faces.keys().last().unwrap() + 1;
+        let mut face_add_count = first_new_key;
+
+        let added_faces = match self.add_horizon_faces(
+            eye_index,
+            &visible_set,
+            &mut assigned_point_indices,
+            &mut face_add_count,
+        ) {
+            Ok(added_faces) => added_faces,
+            Err(err) => {
+                for key in first_new_key..face_add_count {
+                    self.faces.remove(&key);
+                }
+                self.faces.extend(hidden_neighbors);
+                self.points.pop();
+                return Err(err);
+            }
+        };
+        self.remove_faces(&visible_set);
+
+        // Only the vertices of the visible faces that are not on the horizon can be buried.
+        if !self.retain_points {
+            let horizon_vertices: HashSet<usize> = added_faces
+                .iter()
+                .flat_map(|key| self.faces[key].indices.iter().copied())
+                .collect();
+            let buried: BTreeSet<usize> = visible_vertices
+                .into_iter()
+                .filter(|i| !horizon_vertices.contains(i))
+                .collect();
+            self.remove_point_indices(&buried);
+        }
+
+        let mut removed_faces: Vec<usize> = visible_set.into_iter().collect();
+        removed_faces.sort_unstable();
+
+        // The new vertex is the last point, even if unused points were removed before it.
+        Ok(InsertResult::Inserted {
+            index: self.points.len() - 1,
+            added_faces,
+            removed_faces,
+        })
+    }
+
+    /// Removes the points with the given indices from the point set, attempting to update the convex hull.
+    ///
+    /// Only the faces touching a removed vertex are recomputed. The hole they leave behind is patched
+    /// with the convex hull of the hole's boundary vertices and any [retained](ConvexHull::try_new_retained)
+    /// interior points.
+    /// If the hole cannot be patched locally, for example because the boundary vertices are coplanar,
+    /// the convex hull is rebuilt from all remaining points.
+    ///
+    /// On error, the convex hull is left unchanged.
+    ///
+    /// ## Errors
+    /// If the remaining points cannot form a valid convex hull.
+    ///
+    /// ## Panics
+    /// If any of the indices is out of bounds.
+    pub fn remove_points(&mut self, indices: &[usize]) -> Result<(), ErrorKind> {
+        let removed: BTreeSet<usize> = indices.iter().copied().collect();
+        if let Some(&last) = removed.last() {
+            assert!(
+                last < self.points.len(),
+                "point index {last} out of bounds for {} points",
+                self.points.len()
+            );
+        } else {
+            return Ok(());
+        }
+
+        let (affected_faces, kept_faces): (BTreeMap<usize, Face>, BTreeMap<usize, Face>) = self
+            .faces
+            .iter()
+            .map(|(key, face)| (*key, face.clone()))
+            .partition(|(_, face)| face.indices.iter().any(|i| removed.contains(i)));
+
+        let mut c_hull = if affected_faces.is_empty() {
+            // Only interior points were removed, so the faces stay the same.
+            let mut c_hull = self.clone();
+            c_hull.remove_point_indices(&removed);
+            c_hull
+        } else if let Ok(mut c_hull) =
+            self.patch_removed_faces(&removed, &affected_faces, kept_faces)
+        {
+            c_hull.remove_point_indices(&removed);
+            c_hull
+        } else {
+            let remaining: Vec<_> = (0..self.points.len())
+                .filter(|i| !removed.contains(i))
+                .map(|i| self.points[i])
+                .collect();
+
+            if remaining.len() <= 3 {
+                return Err(ErrorKind::Degenerated);
+            }
+
+            let mut c_hull = Self::init_tetrahedron(&remaining)?;
+            c_hull.retain_points = self.retain_points;
+            c_hull.update(None)?;
+            c_hull
+        };
+
+        c_hull.finish_update()?;
+
+        *self = c_hull;
+
+        Ok(())
+    }
+
+    /// Tries to fill the hole left by the `affected_faces` touching the `removed` points,
+    /// returning a convex hull containing the `kept_faces` and the faces of the patch.
+    ///
+    /// The removed points are still present in the returned hull, but no face references them.
+    fn patch_removed_faces(
+        &self,
+        removed: &BTreeSet<usize>,
+        affected_faces: &BTreeMap<usize, Face>,
+        mut kept_faces: BTreeMap<usize, Face>,
+    ) -> Result<Self, ErrorKind> {
+        if kept_faces.is_empty() {
+            return Err(ErrorKind::Degenerated);
+        }
+
+        // The new faces can only use the vertices on the boundary of the hole and the interior points,
+        // as the vertices surrounded by kept faces cannot gain any new faces.
+        let vertices = self.vertex_indices();
+        let boundary: BTreeSet<usize> = affected_faces
+            .values()
+            .flat_map(|face| face.indices.iter().copied())
+            .filter(|i| !removed.contains(i))
+            .collect();
+        let candidates: Vec<usize> = (0..self.points.len())
+            .filter(|i| !removed.contains(i) && (boundary.contains(i) || !vertices.contains(i)))
+            .collect();
+
+        if candidates.len() <= 3 {
+            return Err(ErrorKind::Degenerated);
+        }
+
+        let candidate_points: Vec<_> = candidates.iter().map(|i| self.points[*i]).collect();
+        let mut patch = Self::init_tetrahedron(&candidate_points)?;
+        patch.update(None)?;
+
+        let kept_edges: HashSet<(usize, usize)> = kept_faces
+            .values()
+            .flat_map(|face| (0..3).map(|i| (face.indices[i], face.indices[(i + 1) % 3])))
+            .collect();
+
+        // Keep the faces of the patch that have every remaining point behind them.
+        // Faces sharing a directed edge with a kept face lie on the kept part of the surface.
+        let mut face_add_count = *kept_faces.keys().last().unwrap() + 1;
+        for patch_face in patch.faces.into_values() {
+            let mut face = patch_face;
+            face.indices = face.indices.iter().map(|i| candidates[*i]).collect();
+            face.outside_points.clear();
+            face.neighbor_faces.clear();
+
+            if (0..3).any(|i| kept_edges.contains(&(face.indices[i], face.indices[(i + 1) % 3]))) {
+                continue;
+            }
+
+            let is_supporting = (0..self.points.len())
+                .filter(|i| !removed.contains(i))
+                .all(|i| position_from_face(&self.points, &face, i) <= 0.0);
+
+            if is_supporting {
+                kept_faces.insert(face_add_count, face);
+                face_add_count += 1;
+            }
+        }
+
+        // Fails if the patch does not close the hole exactly.
+        link_neighbors(&mut kept_faces)?;
 
-        if self.points.len() <= 3 {
+        Ok(Self {
+            points: self.points.clone(),
+            faces: kept_faces,
+            retain_points: self.retain_points,
+        })
+    }
+
+    /// Removes the unused points, unless they are retained, and checks that enough vertices remain.
+    fn finish_update(&mut self) -> Result<(), ErrorKind> {
+        if !self.retain_points {
+            self.remove_unused_points();
+        }
+
+        if self.vertex_indices().len() <= 3 {
             return Err(ErrorKind::Degenerated);
         }
 
         Ok(())
     }
 
+    /// Classifies each point in [`ConvexHull::points`] by its position relative to the convex hull.
+    ///
+    /// Points that are not vertices are [`PointKind::Coplanar`] if they are at most `tolerance` away
+    /// from the plane of a face, or exactly on it for a `tolerance` of zero, and [`PointKind::Interior`]
+    /// otherwise. Without [retained points](ConvexHull::try_new_retained), every point is a vertex.
+    #[must_use]
+    pub fn classify_points(&self, tolerance: f64) -> Vec<PointKind> {
+        let vertices = self.vertex_indices();
+
+        (0..self.points.len())
+            .map(|i| {
+                if vertices.contains(&i) {
+                    return PointKind::Vertex;
+                }
+
+                let near_surface = self.faces.values().any(|face| {
+                    let position = position_from_face(&self.points, face, i);
+                    position == 0.0 || position >= -tolerance * face.normal.length()
+                });
+
+                if near_surface {
+                    PointKind::Coplanar
+                } else {
+                    PointKind::Interior
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the indices of the points that are vertices of at least one face.
+    fn vertex_indices(&self) -> BTreeSet<usize> {
+        self.faces
+            .values()
+            .flat_map(|face| face.indices.iter().copied())
+            .collect()
+    }
+
+    /// Returns the edges of the convex hull, each as its two vertex indices
+    /// and the keys of the two faces sharing it.
+    ///
+    /// The first face traverses the edge from the first to the second vertex.
+    pub(crate) fn edges(&self) -> Vec<([usize; 2], [usize; 2])> {
+        let mut directed_edges: HashMap<(usize, usize), usize> = HashMap::new();
+        for (key, face) in &self.faces {
+            for (i, a) in face.indices.iter().enumerate() {
+                let b = face.indices[(i + 1) % face.indices.len()];
+                directed_edges.insert((*a, b), *key);
+            }
+        }
+
+        let mut edges: Vec<_> = directed_edges
+            .iter()
+            .filter(|((a, b), _)| a < b)
+            .filter_map(|((a, b), key)| {
+                let neighbor_key = directed_edges.get(&(*b, *a))?;
+                Some(([*a, *b], [*key, *neighbor_key]))
+            })
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Removes the points with the given indices from the point set, remapping the face indices.
+    ///
+    /// None of the `removed` points may be a vertex of a face.
+    fn remove_point_indices(&mut self, removed: &BTreeSet<usize>) {
+        if removed.is_empty() {
+            return;
+        }
+
+        let mut new_indices = Vec::with_capacity(self.points.len());
+        let mut num_kept = 0;
+        for i in 0..self.points.len() {
+            new_indices.push(num_kept);
+            if !removed.contains(&i) {
+                num_kept += 1;
+            }
+        }
+
+        for face in self.faces.values_mut() {
+            for i in &mut face.indices {
+                debug_assert!(
+                    !removed.contains(i),
+                    "removed points must not be face vertices"
+                );
+                *i = new_indices[*i];
+            }
+        }
+
+        let mut i = 0;
+        self.points.retain(|_| {
+            let keep = !removed.contains(&i);
+            i += 1;
+            keep
+        });
+    }
+
+    /// Returns an iterator over the faces of the convex hull and their keys.
+    ///
+    /// The keys of the faces stay the same while the convex hull is updated,
+    /// but the key of a removed face may be reused for a new face.
+    pub fn faces(&self) -> impl Iterator<Item = (usize, &Face)> {
+        self.faces.iter().map(|(key, face)| (*key, face))
+    }
+
+    /// Returns the face with the given key, if it exists.
+    #[must_use]
+    pub fn face(&self, key: usize) -> Option<&Face> {
+        self.faces.get(&key)
+    }
+
     /// Returns the vertices and indices of the convex hull.
     #[must_use]
     pub fn vertices_indices(self) -> (Vec<DVec3>, Vec<usize>) {
@@ -745,6 +1252,41 @@ fn compute_horizon(
     Ok(horizon)
 }
 
+/// Links the neighbors of the given faces through their shared edges.
+///
+/// Fails if the faces do not form a closed surface, where each edge is shared by exactly two faces
+/// that traverse it in opposite directions.
+fn link_neighbors(faces: &mut BTreeMap<usize, Face>) -> Result<(), ErrorKind> {
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+    for (key, face) in faces.iter() {
+        for (i, a) in face.indices.iter().enumerate() {
+            let b = face.indices[(i + 1) % face.indices.len()];
+            if edges.insert((*a, b), *key).is_some() {
+                return Err(ErrorKind::RoundOffError(
+                    "edge shared by more than two faces",
+                ));
+            }
+        }
+    }
+
+    for face in faces.values_mut() {
+        face.neighbor_faces.clear();
+    }
+
+    for ((a, b), key) in &edges {
+        let Some(neighbor_key) = edges.get(&(*b, *a)) else {
+            return Err(ErrorKind::RoundOffError("edge without a neighboring face"));
+        };
+        faces
+            .get_mut(key)
+            .unwrap()
+            .neighbor_faces
+            .push(*neighbor_key);
+    }
+
+    Ok(())
+}
+
 trait ToRobust {
     fn to_robust(self) -> robust::Coord3D<f64>;
 }
@@ -0,0 +1,165 @@
+//! Minkowski sums and differences of convex hulls.
+
+use glam::DVec3;
+
+use crate::{ConvexHull, ErrorKind};
+
+/// The relative tolerance used to collect every vertex that is tied for the support in a direction.
+///
+/// Collecting too many vertices only adds candidates that end up inside the Minkowski sum,
+/// while missing one would lose a vertex, so the tolerance errs on the large side.
+const SUPPORT_TOLERANCE: f64 = 1e-9;
+
+impl ConvexHull {
+    /// Computes the Minkowski sum of two convex hulls, the set of all sums `a + b`
+    /// of a point `a` in the first hull and a point `b` in the second hull.
+    ///
+    /// Instead of computing the convex hull of all pairwise sums of vertices, only the pairs whose
+    /// normal cones overlap are considered. These are found by walking the face normals of each hull
+    /// along with the supporting vertices of the other hull, and the pairs of edges whose arcs
+    /// on the Gauss map cross.
+    ///
+    /// ## Errors
+    /// If computing the convex hull of the candidate vertices fails.
+    pub fn minkowski_sum(a: &ConvexHull, b: &ConvexHull) -> Result<Self, ErrorKind> {
+        let vertices_a = hull_vertices(a);
+        let vertices_b = hull_vertices(b);
+
+        let mut candidates = Vec::new();
+
+        // A face of one hull combined with the supporting vertices of the other hull.
+        for (face_hull, face_vertices, other_vertices, swap) in [
+            (a, &vertices_a, &vertices_b, false),
+            (b, &vertices_b, &vertices_a, true),
+        ] {
+            for (_, face) in face_hull.faces() {
+                let direction = face.normal.normalize_or_zero();
+                let supports = supporting_vertices(other_vertices, direction);
+                let face_vertices = supporting_vertices(face_vertices, direction);
+
+                for p in &face_vertices {
+                    for q in &supports {
+                        candidates.push(if swap { *q + *p } else { *p + *q });
+                    }
+                }
+            }
+        }
+
+        // Pairs of edges whose normal arcs cross have a common supporting direction.
+        let arcs_a = edge_arcs(a);
+        let arcs_b = edge_arcs(b);
+        for (edge_a, arc_a) in &arcs_a {
+            for (edge_b, arc_b) in &arcs_b {
+                if arcs_intersect(*arc_a, *arc_b) {
+                    for i in edge_a {
+                        for j in edge_b {
+                            candidates.push(a.points[*i] + b.points[*j]);
+                        }
+                    }
+                }
+            }
+        }
+
+        Self::try_new(&candidates, None)
+    }
+
+    /// Computes the Minkowski difference of two convex hulls, the set of all differences `a - b`
+    /// of a point `a` in the first hull and a point `b` in the second hull.
+    ///
+    /// This is the Minkowski sum of the first hull and the second hull reflected through the origin.
+    /// The two hulls overlap if and only if the difference contains the origin.
+    ///
+    /// ## Errors
+    /// If computing the convex hull of the candidate vertices fails.
+    pub fn minkowski_difference(a: &ConvexHull, b: &ConvexHull) -> Result<Self, ErrorKind> {
+        Self::minkowski_sum(a, &b.reflected())
+    }
+
+    /// Returns the convex hull reflected through the origin.
+    pub(crate) fn reflected(&self) -> Self {
+        let mut c_hull = self.clone();
+
+        for point in &mut c_hull.points {
+            *point = -*point;
+        }
+
+        // A point reflection turns the faces inside out, so their winding is reversed.
+        // The plane offsets stay the same, as both the normals and the points are negated.
+        for face in c_hull.faces.values_mut() {
+            face.indices.swap(0, 1);
+            face.normal = -face.normal;
+            face.outside_points.clear();
+        }
+
+        c_hull
+    }
+}
+
+/// Returns the positions of the vertices of the convex hull.
+pub(crate) fn hull_vertices(c_hull: &ConvexHull) -> Vec<DVec3> {
+    c_hull
+        .vertex_indices()
+        .into_iter()
+        .map(|i| c_hull.points[i])
+        .collect()
+}
+
+/// Returns the vertices that are furthest in the given direction, within the support tolerance.
+fn supporting_vertices(vertices: &[DVec3], direction: DVec3) -> Vec<DVec3> {
+    let max = vertices
+        .iter()
+        .map(|p| p.dot(direction))
+        .fold(f64::NEG_INFINITY, f64::max);
+    let scale = vertices
+        .iter()
+        .map(|p| p.abs().max_element())
+        .fold(0.0, f64::max);
+    let tolerance = SUPPORT_TOLERANCE * scale.max(f64::MIN_POSITIVE);
+
+    vertices
+        .iter()
+        .filter(|p| p.dot(direction) >= max - tolerance)
+        .copied()
+        .collect()
+}
+
+/// Returns the edges of the convex hull, as the indices of their vertices,
+/// along with the arc they span on the Gauss map,
+/// which goes from the unit normal of one neighboring face to the unit normal of the other.
+///
+/// Edges between coplanar faces span no arc and are left out.
+pub(crate) fn edge_arcs(c_hull: &ConvexHull) -> Vec<([usize; 2], [DVec3; 2])> {
+    c_hull
+        .edges()
+        .into_iter()
+        .filter_map(|([i, j], [face_a, face_b])| {
+            let normal_a = c_hull.faces[&face_a].normal.normalize_or_zero();
+            let normal_b = c_hull.faces[&face_b].normal.normalize_or_zero();
+            if normal_a.cross(normal_b).length_squared() <= SUPPORT_TOLERANCE {
+                return None;
+            }
+            Some(([i, j], [normal_a, normal_b]))
+        })
+        .collect()
+}
+
+/// Checks whether two arcs on the unit sphere intersect. Each arc is shorter than half a great circle.
+pub(crate) fn arcs_intersect([a1, a2]: [DVec3; 2], [b1, b2]: [DVec3; 2]) -> bool {
+    let plane_a = a1.cross(a2);
+    let plane_b = b1.cross(b2);
+    let direction = plane_a.cross(plane_b);
+
+    if direction.length_squared() <= SUPPORT_TOLERANCE * SUPPORT_TOLERANCE {
+        // The arcs are on the same great circle. Such edges are parallel, so their sums
+        // are already covered by the face normals at the ends of the arcs.
+        return false;
+    }
+
+    let on_arc = |[p1, p2]: [DVec3; 2], plane: DVec3, d: DVec3| {
+        p1.cross(d).dot(plane) >= 0.0 && d.cross(p2).dot(plane) >= 0.0
+    };
+
+    [direction, -direction]
+        .into_iter()
+        .any(|d| on_arc([a1, a2], plane_a, d) && on_arc([b1, b2], plane_b, d))
+}
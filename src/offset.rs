@@ -0,0 +1,193 @@
+//! Offsetting the faces of convex hulls.
+
+use glam::{DMat4, DVec3, DVec4};
+
+use crate::collision::project;
+use crate::minkowski::hull_vertices;
+use crate::{ConvexHull, ErrorKind};
+
+impl ConvexHull {
+    /// Computes the convex hull with every face plane moved outward by `margin`, or inward
+    /// if the margin is negative.
+    ///
+    /// The vertices are recomputed as the intersection of the moved half-spaces,
+    /// so the faces keep their orientation, unlike when scaling the points. When moving inward,
+    /// faces that shrink away entirely are removed.
+    ///
+    /// ## Errors
+    /// If the convex hull shrinks away entirely, [`ErrorKind::EmptyIntersection`] is returned,
+    /// and if round-off errors keep a point inside the shrunk faces from being found,
+    /// [`ErrorKind::RoundOffError`]. Otherwise, if computing the intersection of the half-spaces fails.
+    pub fn offset(&self, margin: f64) -> Result<Self, ErrorKind> {
+        let vertices = hull_vertices(self);
+
+        // The offsets of thin faces are inaccurate, so the planes are moved from the furthest vertex.
+        let planes: Vec<(DVec3, f64)> = self
+            .faces()
+            .filter_map(|(_, face)| face.normal.try_normalize())
+            .map(|normal| (normal, project(&vertices, normal).1 + margin))
+            .collect();
+
+        // When shrinking, the centroid of the vertices can end up outside of thin convex hulls,
+        // so the point furthest inside the moved planes is used instead.
+        let interior_point = if margin >= 0.0 {
+            vertices.iter().sum::<DVec3>() / vertices.len() as f64
+        } else {
+            match chebyshev_center(&planes) {
+                Some((_, depth)) if depth <= 0.0 => return Err(ErrorKind::EmptyIntersection),
+                Some((center, _)) => center,
+                None => {
+                    return Err(ErrorKind::RoundOffError(
+                        "no point found inside the offset faces",
+                    ))
+                }
+            }
+        };
+
+        Self::from_half_spaces(&planes, interior_point)
+    }
+}
+
+/// Finds the Chebyshev center of the half-spaces `normal.dot(x) <= offset` with unit normals,
+/// the point furthest inside all of them, along with its distance from the closest plane,
+/// which is negative if the half-spaces do not intersect.
+///
+/// The center and the distance `t` maximize `t` subject to `normal.dot(x) + t <= offset` for every
+/// half-space. This linear program is solved through its dual, which minimizes the sum of `y * offset`
+/// subject to `y >= 0` for every half-space, with the sums of `y * normal` and `y` being zero and one.
+/// The dual is solved with the simplex method, and the four half-spaces in its optimal basis
+/// are the planes touching the largest ball inside the half-spaces, which determine its center.
+///
+/// Returns `None` if the half-spaces do not bound a finite volume, or round-off errors
+/// stop the simplex method from converging.
+fn chebyshev_center(planes: &[(DVec3, f64)]) -> Option<(DVec3, f64)> {
+    let m = planes.len();
+    let scale = planes
+        .iter()
+        .map(|(_, offset)| offset.abs())
+        .fold(f64::MIN_POSITIVE, f64::max);
+
+    // The constraints of the dual as rows of a tableau, followed by an artificial variable for each row
+    // that starts out in the basis, and the right-hand side.
+    let mut tableau: Vec<Vec<f64>> = (0..4)
+        .map(|row| {
+            let mut entries: Vec<f64> = planes
+                .iter()
+                .map(|(normal, _)| if row < 3 { normal[row] } else { 1.0 })
+                .collect();
+            entries.extend((0..4).map(|artificial| if artificial == row { 1.0 } else { 0.0 }));
+            entries.push(if row < 3 { 0.0 } else { 1.0 });
+            entries
+        })
+        .collect();
+    let mut basis: [usize; 4] = [m, m + 1, m + 2, m + 3];
+
+    // The first phase minimizes the artificial variables to find a feasible basis, and the second phase
+    // minimizes the objective of the dual without letting the artificial variables back in.
+    let artificial_costs: Vec<f64> = (0..m + 4).map(|j| if j < m { 0.0 } else { 1.0 }).collect();
+    simplex(
+        &mut tableau,
+        &mut basis,
+        &artificial_costs,
+        m + 4,
+        SIMPLEX_TOLERANCE,
+    )?;
+    if tableau
+        .iter()
+        .zip(basis)
+        .any(|(row, j)| j >= m && row[m + 4] > SIMPLEX_TOLERANCE)
+    {
+        return None;
+    }
+    for row in 0..4 {
+        if basis[row] >= m {
+            let j = (0..m).find(|j| tableau[row][*j].abs() > SIMPLEX_TOLERANCE)?;
+            pivot(&mut tableau, &mut basis, row, j);
+        }
+    }
+
+    let costs: Vec<f64> = (0..m + 4)
+        .map(|j| if j < m { planes[j].1 } else { 0.0 })
+        .collect();
+    simplex(
+        &mut tableau,
+        &mut basis,
+        &costs,
+        m,
+        SIMPLEX_TOLERANCE * scale,
+    )?;
+
+    // The planes of the basis are tight at the center: `normal.dot(x) + t == offset`.
+    let columns = DMat4::from_cols_array_2d(&basis.map(|j| planes[j].0.extend(1.0).to_array()));
+    let offsets = DVec4::from_array(basis.map(|j| planes[j].1));
+    if columns.determinant().abs() <= SIMPLEX_TOLERANCE {
+        return None;
+    }
+    let solution = columns.transpose().inverse() * offsets;
+    Some((solution.truncate(), solution.w))
+}
+
+/// The tolerance for the entries and reduced costs of the simplex tableau, relative to the unit normals.
+const SIMPLEX_TOLERANCE: f64 = 1e-12;
+
+/// Runs the simplex method on the tableau to minimize the given costs of the variables,
+/// letting only the variables before `enterable` into the basis.
+///
+/// Bland's rule, always picking the variables with the smallest indices, keeps the method from cycling
+/// through degenerate bases. Returns `None` if the objective is unbounded or the method does not converge.
+fn simplex(
+    tableau: &mut [Vec<f64>],
+    basis: &mut [usize; 4],
+    costs: &[f64],
+    enterable: usize,
+    tolerance: f64,
+) -> Option<()> {
+    let rhs = tableau[0].len() - 1;
+
+    for _ in 0..SIMPLEX_ITERATIONS_PER_VARIABLE * rhs {
+        let reduced_cost = |j: usize| {
+            costs[j]
+                - tableau
+                    .iter()
+                    .zip(basis.iter())
+                    .map(|(row, i)| costs[*i] * row[j])
+                    .sum::<f64>()
+        };
+        let Some(entering) = (0..enterable).find(|j| reduced_cost(*j) < -tolerance) else {
+            return Some(());
+        };
+
+        let leaving = (0..tableau.len())
+            .filter(|row| tableau[*row][entering] > SIMPLEX_TOLERANCE)
+            .min_by(|a, b| {
+                let ratio = |row: usize| tableau[row][rhs] / tableau[row][entering];
+                ratio(*a)
+                    .total_cmp(&ratio(*b))
+                    .then(basis[*a].cmp(&basis[*b]))
+            })?;
+        pivot(tableau, basis, leaving, entering);
+    }
+
+    None
+}
+
+/// The maximum number of pivots of the simplex method for each variable of the tableau.
+const SIMPLEX_ITERATIONS_PER_VARIABLE: usize = 16;
+
+/// Replaces the basic variable of the `row` with the variable in the `column`.
+fn pivot(tableau: &mut [Vec<f64>], basis: &mut [usize; 4], row: usize, column: usize) {
+    let pivot = tableau[row][column];
+    tableau[row].iter_mut().for_each(|entry| *entry /= pivot);
+
+    let pivot_row = tableau[row].clone();
+    for (i, other) in tableau.iter_mut().enumerate() {
+        let factor = other[column];
+        if i != row && factor != 0.0 {
+            other
+                .iter_mut()
+                .zip(&pivot_row)
+                .for_each(|(entry, p)| *entry -= factor * p);
+        }
+    }
+    basis[row] = column;
+}
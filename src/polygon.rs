@@ -0,0 +1,69 @@
+//! Convex polygons in the plane.
+
+use glam::DVec2;
+
+/// Computes the convex hull of points in the plane with Andrew's monotone chain algorithm.
+///
+/// Returns the indices of the vertices in counterclockwise order, starting from the leftmost point.
+/// Duplicate points, and points on the boundary between two vertices, are left out.
+pub(crate) fn convex_hull_2d(points: &[DVec2]) -> Vec<usize> {
+    let mut sorted: Vec<usize> = (0..points.len()).collect();
+    sorted.sort_by(|a, b| {
+        let (a, b) = (points[*a], points[*b]);
+        a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+    });
+    sorted.dedup_by(|a, b| points[*a] == points[*b]);
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let orientation = |a: usize, b: usize, c: usize| {
+        let [a, b, c] = [a, b, c].map(|i| robust::Coord {
+            x: points[i].x,
+            y: points[i].y,
+        });
+        robust::orient2d(a, b, c)
+    };
+
+    let mut hull: Vec<usize> = Vec::with_capacity(sorted.len() + 1);
+
+    // The lower chain from left to right, followed by the upper chain from right to left.
+    // The upper chain may remove points down to the rightmost point, but not past it.
+    let reversed: Vec<usize> = sorted.iter().rev().copied().collect();
+    for chain in [&sorted[..], &reversed[1..]] {
+        let min_len = hull.len().max(1);
+        for &i in chain {
+            while hull.len() > min_len
+                && orientation(hull[hull.len() - 2], hull[hull.len() - 1], i) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(i);
+        }
+    }
+
+    // The upper chain ends at the leftmost point the lower chain started from.
+    hull.pop();
+    hull
+}
+
+/// Clips a convex polygon with the line `normal.dot(x) == offset`, keeping the part behind the line.
+///
+/// The vertices keep their order. The polygon is empty if nothing of it is behind the line,
+/// and it may degenerate into a single point or a segment if it only touches the line.
+pub(crate) fn clip_polygon(polygon: &[DVec2], normal: DVec2, offset: f64) -> Vec<DVec2> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let (distance_a, distance_b) = (normal.dot(*a) - offset, normal.dot(b) - offset);
+
+        if distance_a <= 0.0 {
+            clipped.push(*a);
+        }
+        if (distance_a < 0.0 && distance_b > 0.0) || (distance_a > 0.0 && distance_b < 0.0) {
+            clipped.push(a.lerp(b, distance_a / (distance_a - distance_b)));
+        }
+    }
+    clipped
+}
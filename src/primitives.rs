@@ -0,0 +1,287 @@
+//! Convex hulls of spheres, capsules, boxes and points.
+
+use glam::{DQuat, DVec3};
+
+use std::collections::HashMap;
+
+use crate::collision::gjk;
+use crate::minkowski::hull_vertices;
+use crate::{ConvexHull, ErrorKind};
+
+/// The largest number of times an icosphere is subdivided, which gives 655362 points per sphere.
+///
+/// Each subdivision quadruples the number of points, so larger values would only exhaust memory.
+const MAX_SUBDIVISIONS: usize = 8;
+
+/// A shape that can be part of the input of [`ConvexHull::try_from_primitives`]
+/// and [`RoundedConvexHull::try_new`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Primitive {
+    /// A single point.
+    Point(DVec3),
+    /// A sphere.
+    Sphere {
+        /// The center of the sphere.
+        center: DVec3,
+        /// The radius of the sphere.
+        radius: f64,
+    },
+    /// A capsule, which is every point within the radius from the segment between `a` and `b`.
+    Capsule {
+        /// The first end of the segment.
+        a: DVec3,
+        /// The second end of the segment.
+        b: DVec3,
+        /// The radius of the capsule.
+        radius: f64,
+    },
+    /// A box, which may be rotated.
+    Cuboid {
+        /// The center of the box.
+        center: DVec3,
+        /// Half of the extent of the box along each of its axes.
+        half_extents: DVec3,
+        /// The rotation of the box around its center.
+        rotation: DQuat,
+    },
+}
+
+impl Primitive {
+    /// The radius by which the primitive is rounded, which is zero for points and boxes.
+    fn radius(&self) -> f64 {
+        match *self {
+            Primitive::Point(_) | Primitive::Cuboid { .. } => 0.0,
+            Primitive::Sphere { radius, .. } | Primitive::Capsule { radius, .. } => radius,
+        }
+    }
+
+    /// Appends points whose convex hull approximates the primitive, with its radius reduced
+    /// by `shrink`, to `points`. Spheres are approximated with the given points on the unit sphere.
+    fn append_points(&self, shrink: f64, sphere: &[DVec3], points: &mut Vec<DVec3>) {
+        let mut append_sphere = |center: DVec3, radius: f64| {
+            let radius = radius - shrink;
+            if radius > 0.0 {
+                points.extend(sphere.iter().map(|p| center + *p * radius));
+            } else {
+                points.push(center);
+            }
+        };
+
+        match *self {
+            Primitive::Point(point) => points.push(point),
+            Primitive::Sphere { center, radius } => append_sphere(center, radius),
+            Primitive::Capsule { a, b, radius } => {
+                append_sphere(a, radius);
+                append_sphere(b, radius);
+            }
+            Primitive::Cuboid {
+                center,
+                half_extents,
+                rotation,
+            } => {
+                points.extend((0..8).map(|i| {
+                    let signs = DVec3::new(
+                        if i & 1 == 0 { -1.0 } else { 1.0 },
+                        if i & 2 == 0 { -1.0 } else { 1.0 },
+                        if i & 4 == 0 { -1.0 } else { 1.0 },
+                    );
+                    center + rotation * (half_extents * signs)
+                }));
+            }
+        }
+    }
+}
+
+impl ConvexHull {
+    /// Computes the convex hull of the primitives, where spheres and the ends of capsules
+    /// are approximated with icospheres.
+    ///
+    /// The icosahedron is subdivided `subdivisions` times, but at most 8 times, so each sphere
+    /// is approximated with `10 * 4^subdivisions + 2` points on it. The convex hull is therefore slightly
+    /// inside of the exact one, by less than the radius times `1 - cos(θ)`, where θ is the largest
+    /// angle between the center of a triangle of the icosphere and its vertices.
+    ///
+    /// ## Errors
+    /// If computing the convex hull of the points fails, for example if there are no primitives.
+    pub fn try_from_primitives(
+        primitives: &[Primitive],
+        subdivisions: usize,
+    ) -> Result<Self, ErrorKind> {
+        let sphere = icosphere(subdivisions);
+        let mut points = Vec::new();
+        for primitive in primitives {
+            primitive.append_points(0.0, &sphere, &mut points);
+        }
+        ConvexHull::try_new(&points, None)
+    }
+}
+
+/// A convex hull rounded by a radius, which is every point within the radius from a convex core.
+///
+/// The core is kept as its points rather than a [`ConvexHull`], so that it can be
+/// a single point, a segment or a polygon, such as for a sphere or a capsule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundedConvexHull {
+    /// The points whose convex hull is the core.
+    pub core: Vec<DVec3>,
+    /// The radius by which the core is rounded.
+    pub radius: f64,
+}
+
+impl RoundedConvexHull {
+    /// Computes the rounded convex hull of the primitives.
+    ///
+    /// The radius is the smallest radius of the primitives, which is zero if there are
+    /// any points or boxes. The core is the convex hull of the primitives with their radii reduced
+    /// by it. When every primitive has the same radius, such as for the spheres of a compound
+    /// collider, the core is exact. Otherwise, the remaining radii are approximated with icospheres
+    /// subdivided `subdivisions` times, as in [`ConvexHull::try_from_primitives`].
+    ///
+    /// ## Errors
+    /// If there are no primitives, [`ErrorKind::Empty`] is returned.
+    pub fn try_new(primitives: &[Primitive], subdivisions: usize) -> Result<Self, ErrorKind> {
+        let radius = primitives
+            .iter()
+            .map(Primitive::radius)
+            .min_by(f64::total_cmp)
+            .ok_or(ErrorKind::Empty)?
+            .max(0.0);
+
+        let sphere = icosphere(subdivisions);
+        let mut points = Vec::new();
+        for primitive in primitives {
+            primitive.append_points(radius, &sphere, &mut points);
+        }
+
+        // Only the vertices of the core are kept. A core without volume keeps all of its points.
+        let core = match ConvexHull::try_new(&points, None) {
+            Ok(c_hull) => hull_vertices(&c_hull),
+            Err(_) => {
+                points.sort_by(|a, b| {
+                    a.x.total_cmp(&b.x)
+                        .then(a.y.total_cmp(&b.y))
+                        .then(a.z.total_cmp(&b.z))
+                });
+                points.dedup();
+                points
+            }
+        };
+
+        Ok(Self { core, radius })
+    }
+
+    /// Returns the point of the rounded convex hull that is furthest in the given direction.
+    #[must_use]
+    pub fn support_point(&self, direction: DVec3) -> DVec3 {
+        self.core_support_point(direction) + direction.normalize_or_zero() * self.radius
+    }
+
+    /// Computes the distance from the point to the rounded convex hull,
+    /// which is zero if the point is inside of it.
+    #[must_use]
+    pub fn distance_to_point(&self, point: DVec3) -> f64 {
+        let (distance, _, _) = gjk(|direction| (self.core_support_point(direction), point));
+        (distance - self.radius).max(0.0)
+    }
+
+    /// Checks whether the point is inside of the rounded convex hull or on its boundary.
+    #[must_use]
+    pub fn contains(&self, point: DVec3) -> bool {
+        self.distance_to_point(point) == 0.0
+    }
+
+    /// Approximates the rounded convex hull with a convex hull, where the rounding is approximated
+    /// with icospheres subdivided `subdivisions` times around each vertex of the core,
+    /// but at most 8 times as in [`ConvexHull::try_from_primitives`].
+    ///
+    /// ## Errors
+    /// If computing the convex hull of the points fails.
+    pub fn to_convex_hull(&self, subdivisions: usize) -> Result<ConvexHull, ErrorKind> {
+        let sphere = icosphere(subdivisions);
+        let mut points = Vec::new();
+        for point in &self.core {
+            Primitive::Sphere {
+                center: *point,
+                radius: self.radius,
+            }
+            .append_points(0.0, &sphere, &mut points);
+        }
+        ConvexHull::try_new(&points, None)
+    }
+
+    fn core_support_point(&self, direction: DVec3) -> DVec3 {
+        *self
+            .core
+            .iter()
+            .max_by(|a, b| a.dot(direction).total_cmp(&b.dot(direction)))
+            .unwrap()
+    }
+}
+
+/// Computes the vertices of an icosahedron subdivided the given number of times,
+/// up to [`MAX_SUBDIVISIONS`], with every vertex projected onto the unit sphere.
+fn icosphere(subdivisions: usize) -> Vec<DVec3> {
+    let phi = (1.0 + 5.0f64.sqrt()) / 2.0;
+    let mut points: Vec<DVec3> = [
+        [-1.0, phi, 0.0],
+        [1.0, phi, 0.0],
+        [-1.0, -phi, 0.0],
+        [1.0, -phi, 0.0],
+        [0.0, -1.0, phi],
+        [0.0, 1.0, phi],
+        [0.0, -1.0, -phi],
+        [0.0, 1.0, -phi],
+        [phi, 0.0, -1.0],
+        [phi, 0.0, 1.0],
+        [-phi, 0.0, -1.0],
+        [-phi, 0.0, 1.0],
+    ]
+    .into_iter()
+    .map(|p| DVec3::from_array(p).normalize())
+    .collect();
+    let mut triangles: Vec<[usize; 3]> = vec![
+        [0, 11, 5],
+        [0, 5, 1],
+        [0, 1, 7],
+        [0, 7, 10],
+        [0, 10, 11],
+        [1, 5, 9],
+        [5, 11, 4],
+        [11, 10, 2],
+        [10, 7, 6],
+        [7, 1, 8],
+        [3, 9, 4],
+        [3, 4, 2],
+        [3, 2, 6],
+        [3, 6, 8],
+        [3, 8, 9],
+        [4, 9, 5],
+        [2, 4, 11],
+        [6, 2, 10],
+        [8, 6, 7],
+        [9, 8, 1],
+    ];
+
+    for _ in 0..subdivisions.min(MAX_SUBDIVISIONS) {
+        // The midpoints are shared by the two triangles of each edge.
+        let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut midpoint = |i: usize, j: usize, points: &mut Vec<DVec3>| {
+            *midpoints.entry((i.min(j), i.max(j))).or_insert_with(|| {
+                points.push((points[i] + points[j]).normalize());
+                points.len() - 1
+            })
+        };
+
+        triangles = triangles
+            .into_iter()
+            .flat_map(|[a, b, c]| {
+                let ab = midpoint(a, b, &mut points);
+                let bc = midpoint(b, c, &mut points);
+                let ca = midpoint(c, a, &mut points);
+                [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+            })
+            .collect();
+    }
+
+    points
+}
@@ -0,0 +1,119 @@
+//! Silhouettes and projections of convex hulls.
+
+use glam::{DVec2, DVec3};
+
+use std::collections::HashMap;
+
+use crate::polygon::convex_hull_2d;
+use crate::{ConvexHull, Face, ToRobust};
+
+impl ConvexHull {
+    /// Computes the silhouette of the convex hull seen from infinitely far away,
+    /// looking in the given direction.
+    ///
+    /// The silhouette is the loop of edges between the faces facing the viewer and the faces facing away.
+    /// It is returned as the indices of its vertices in [`ConvexHull::points`], in counterclockwise order
+    /// as seen by the viewer.
+    #[must_use]
+    pub fn silhouette(&self, direction: DVec3) -> Vec<usize> {
+        self.horizon_loop(|face| face.normal.dot(direction) < 0.0)
+    }
+
+    /// Computes the silhouette of the convex hull seen from the given eye point,
+    /// like the horizon of a point added to the convex hull.
+    ///
+    /// Returns the indices of the vertices of the silhouette in [`ConvexHull::points`],
+    /// in counterclockwise order as seen from the eye point. If the eye point is inside
+    /// or on the surface of the convex hull, the silhouette is empty.
+    #[must_use]
+    pub fn silhouette_from_point(&self, eye: DVec3) -> Vec<usize> {
+        self.horizon_loop(|face| {
+            let [p0, p1, p2] = [0, 1, 2].map(|i| self.points[face.indices[i]].to_robust());
+            -robust::orient3d(p0, p1, p2, eye.to_robust()) > 0.0
+        })
+    }
+
+    /// Computes the convex polygon outlining the orthographic projection of the convex hull
+    /// onto a plane with the given normal, in counterclockwise order around the normal.
+    ///
+    /// The polygon is expressed in the basis returned by [`plane_basis`] for the normal.
+    ///
+    /// ## Panics
+    /// If the normal is zero or not finite.
+    #[must_use]
+    pub fn project_onto_plane(&self, normal: DVec3) -> Vec<DVec2> {
+        let (u, v) = plane_basis(normal);
+        let projected: Vec<DVec2> = self
+            .vertex_indices()
+            .into_iter()
+            .map(|i| DVec2::new(self.points[i].dot(u), self.points[i].dot(v)))
+            .collect();
+
+        convex_hull_2d(&projected)
+            .into_iter()
+            .map(|i| projected[i])
+            .collect()
+    }
+
+    /// Chains the edges between the faces for which `is_visible` is true and the other faces into a loop,
+    /// following the winding of the visible faces.
+    ///
+    /// Returns an empty loop if no face or every face is visible, or if the edges do not form a single loop.
+    fn horizon_loop(&self, is_visible: impl Fn(&Face) -> bool) -> Vec<usize> {
+        let visible: HashMap<usize, bool> = self
+            .faces
+            .iter()
+            .map(|(key, face)| (*key, is_visible(face)))
+            .collect();
+
+        let mut next = HashMap::new();
+        for ([a, b], [face_a, face_b]) in self.edges() {
+            match (visible[&face_a], visible[&face_b]) {
+                (true, false) => next.insert(a, b),
+                (false, true) => next.insert(b, a),
+                _ => continue,
+            };
+        }
+
+        let Some(start) = next.keys().min().copied() else {
+            return Vec::new();
+        };
+
+        let mut horizon = vec![start];
+        let mut current = next[&start];
+        while current != start {
+            if horizon.len() >= next.len() {
+                return Vec::new();
+            }
+            horizon.push(current);
+            match next.get(&current) {
+                Some(vertex) => current = *vertex,
+                None => return Vec::new(),
+            }
+        }
+
+        if horizon.len() == next.len() {
+            horizon
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Returns an orthonormal basis `(u, v)` of the plane with the given normal,
+/// where `u.cross(v)` points along the normal.
+///
+/// A point `p` in the plane has the coordinates `(p.dot(u), p.dot(v))` in this basis.
+///
+/// ## Panics
+/// If the normal is zero or not finite.
+#[must_use]
+pub fn plane_basis(normal: DVec3) -> (DVec3, DVec3) {
+    assert!(
+        normal.is_finite() && normal != DVec3::ZERO,
+        "plane normal {normal} must be nonzero and finite"
+    );
+    let normal = normal.normalize();
+    let u = normal.any_orthonormal_vector();
+    (u, normal.cross(u))
+}
@@ -0,0 +1,298 @@
+//! Convex hulls with simplicial facets in any dimension.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+
+use crate::ErrorKind;
+
+/// An orientation predicate for points identified by their indices.
+pub(crate) trait Orientation {
+    /// Data about the hyperplane through the points of a facet, which is reused between orientation tests.
+    type Plane: Clone + fmt::Debug;
+
+    /// The dimension of the points.
+    fn dimension(&self) -> usize;
+
+    /// Computes the [`Orientation::Plane`] through the points of the `facet`.
+    fn plane(&self, facet: &[usize]) -> Self::Plane;
+
+    /// Returns a positive value if the `point` is in front of the hyperplane through the points
+    /// of the `facet`, a negative value if it is behind it, and zero if it is on it.
+    ///
+    /// This is the sign of the determinant of the homogeneous coordinates of the facet's points
+    /// followed by the `point`, so swapping two points of the facet flips the sign.
+    /// The sign must be exact, while the magnitude should grow with the distance from the hyperplane.
+    fn orient(&self, facet: &[usize], plane: &Self::Plane, point: usize) -> f64;
+}
+
+/// A facet of a [`SimplicialHull`].
+#[derive(Debug, Clone)]
+pub(crate) struct Facet<P> {
+    /// The indices of the facet's points, ordered so that the points outside of the hull are in front of it.
+    pub(crate) vertices: Vec<usize>,
+    /// The keys of the neighboring facets, each sharing every vertex except the one at the same position.
+    neighbors: Vec<usize>,
+    /// The hyperplane through the facet's points.
+    plane: P,
+    /// The indices of the points in front of the facet that are not assigned to another facet,
+    /// and their orientation relative to it.
+    outside_points: Vec<(usize, f64)>,
+}
+
+/// A convex hull in any dimension whose facets are simplices.
+///
+/// The points are only accessed through an [`Orientation`] predicate, so the same machinery
+/// works for points in any dimension, or for points that are only implicitly lifted to a higher dimension.
+#[derive(Debug, Clone)]
+pub(crate) struct SimplicialHull<P> {
+    facets: BTreeMap<usize, Facet<P>>,
+}
+
+impl<P> SimplicialHull<P> {
+    /// Computes the convex hull of the `simplex` and the given `points` with the quickhull algorithm.
+    ///
+    /// The `simplex` must consist of one more point than the dimension, and enclose a nonzero volume.
+    ///
+    /// ## Errors
+    /// If the dimension is less than two, if the simplex is flat, or if the facets cannot be linked
+    /// because the orientation predicate is inconsistent.
+    pub(crate) fn try_new(
+        orientation: &impl Orientation<Plane = P>,
+        simplex: &[usize],
+        points: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, ErrorKind> {
+        let dimension = orientation.dimension();
+        debug_assert_eq!(simplex.len(), dimension + 1);
+
+        // The orientation of a facet with a single point cannot be flipped by reordering it.
+        if dimension < 2 {
+            return Err(ErrorKind::Degenerated);
+        }
+
+        // The facet opposite to each point of the simplex has that point behind it.
+        // The neighbor opposite to each point of a facet is the facet opposite to the same point.
+        let mut facets = BTreeMap::new();
+        for (key, opposite) in simplex.iter().enumerate() {
+            let mut vertices: Vec<usize> =
+                simplex.iter().copied().filter(|i| i != opposite).collect();
+            let mut neighbors: Vec<usize> = (0..simplex.len()).filter(|i| *i != key).collect();
+
+            let mut plane = orientation.plane(&vertices);
+            let position = orientation.orient(&vertices, &plane, *opposite);
+            if position == 0.0 {
+                return Err(ErrorKind::Degenerated);
+            } else if position > 0.0 {
+                vertices.swap(0, 1);
+                neighbors.swap(0, 1);
+                plane = orientation.plane(&vertices);
+            }
+
+            facets.insert(
+                key,
+                Facet {
+                    vertices,
+                    neighbors,
+                    plane,
+                    outside_points: Vec::new(),
+                },
+            );
+        }
+
+        let mut hull = Self { facets };
+        let keys: Vec<usize> = hull.facets.keys().copied().collect();
+        let points: Vec<usize> = points
+            .into_iter()
+            .filter(|i| !simplex.contains(i))
+            .collect();
+        hull.assign_outside_points(orientation, &keys, points);
+        hull.update(orientation)?;
+
+        Ok(hull)
+    }
+
+    /// Returns an iterator over the facets of the convex hull.
+    pub(crate) fn facets(&self) -> impl Iterator<Item = &Facet<P>> {
+        self.facets.values()
+    }
+
+    /// Assigns each of the `points` to the first facet with one of the given `keys` that it is in front of.
+    ///
+    /// Points that are behind every one of the facets are dropped.
+    fn assign_outside_points(
+        &mut self,
+        orientation: &impl Orientation<Plane = P>,
+        keys: &[usize],
+        points: impl IntoIterator<Item = usize>,
+    ) {
+        for point in points {
+            for key in keys {
+                let facet = self.facets.get_mut(key).unwrap();
+                let position = orientation.orient(&facet.vertices, &facet.plane, point);
+                if position > 0.0 {
+                    facet.outside_points.push((point, position));
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Runs the main loop of the quickhull algorithm until no facet has outside points left.
+    ///
+    /// This is the same as the loop in [`ConvexHull::update`](crate::ConvexHull), except that the ridges
+    /// of the horizon have one point less than the dimension instead of always being edges.
+    fn update(&mut self, orientation: &impl Orientation<Plane = P>) -> Result<(), ErrorKind> {
+        let mut facet_add_count = *self.facets.keys().last().unwrap() + 1;
+
+        while let Some((key, facet)) = self
+            .facets
+            .iter()
+            .find(|(_, facet)| !facet.outside_points.is_empty())
+            .map(|(key, facet)| (*key, facet))
+        {
+            // Select the furthest point.
+            let (eye_index, _) = *facet
+                .outside_points
+                .iter()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .unwrap();
+
+            let visible_set = self.visible_set(orientation, key, eye_index);
+            let new_keys = self.add_horizon_facets(
+                orientation,
+                eye_index,
+                &visible_set,
+                &mut facet_add_count,
+            )?;
+
+            // Assign the orphaned points to the new facets, and remove the old facets.
+            let mut orphans = Vec::new();
+            for visible in &visible_set {
+                let visible_facet = self.facets.remove(visible).unwrap();
+                orphans.extend(
+                    visible_facet
+                        .outside_points
+                        .into_iter()
+                        .map(|(i, _)| i)
+                        .filter(|i| *i != eye_index),
+                );
+            }
+            self.assign_outside_points(orientation, &new_keys, orphans);
+        }
+
+        Ok(())
+    }
+
+    /// Computes the keys of the facets that are visible from the point at `eye_index`,
+    /// starting from the visible facet with the given `key`.
+    fn visible_set(
+        &self,
+        orientation: &impl Orientation<Plane = P>,
+        key: usize,
+        eye_index: usize,
+    ) -> BTreeSet<usize> {
+        let mut visible_set = BTreeSet::from([key]);
+        let mut visited = BTreeSet::from([key]);
+        let mut neighbor_stack = self.facets[&key].neighbors.clone();
+
+        while let Some(neighbor_key) = neighbor_stack.pop() {
+            if !visited.insert(neighbor_key) {
+                continue;
+            }
+
+            let neighbor = &self.facets[&neighbor_key];
+            if orientation.orient(&neighbor.vertices, &neighbor.plane, eye_index) > 0.0 {
+                visible_set.insert(neighbor_key);
+                neighbor_stack.extend(neighbor.neighbors.iter().copied());
+            }
+        }
+
+        visible_set
+    }
+
+    /// Creates facets connecting the horizon of the `visible_set` to the point at `eye_index`,
+    /// returning the keys of the new facets.
+    ///
+    /// The visible facets are left in place, so that their outside points can still be reassigned.
+    fn add_horizon_facets(
+        &mut self,
+        orientation: &impl Orientation<Plane = P>,
+        eye_index: usize,
+        visible_set: &BTreeSet<usize>,
+        facet_add_count: &mut usize,
+    ) -> Result<Vec<usize>, ErrorKind> {
+        // Each ridge of the horizon is a visible facet without the point opposite to a hidden neighbor.
+        // Replacing that point with the eye point keeps the orientation, as the eye point is on the same
+        // side of the ridge's other facet as the replaced point.
+        let mut new_facets = Vec::new();
+        for visible_key in visible_set {
+            let visible = &self.facets[visible_key];
+            for (i, neighbor_key) in visible.neighbors.iter().enumerate() {
+                if visible_set.contains(neighbor_key) {
+                    continue;
+                }
+
+                let mut vertices = visible.vertices.clone();
+                vertices[i] = eye_index;
+                let mut neighbors = vec![usize::MAX; vertices.len()];
+                neighbors[i] = *neighbor_key;
+
+                new_facets.push((*visible_key, i, vertices, neighbors));
+            }
+        }
+
+        // Link the new facets to the hidden neighbors across the horizon.
+        let mut new_keys = Vec::with_capacity(new_facets.len());
+        for (visible_key, i, _, neighbors) in &new_facets {
+            let new_key = *facet_add_count;
+            *facet_add_count += 1;
+            new_keys.push(new_key);
+
+            let neighbor = self.facets.get_mut(&neighbors[*i]).unwrap();
+            let Some(slot) = neighbor.neighbors.iter_mut().find(|k| *k == visible_key) else {
+                return Err(ErrorKind::RoundOffError(
+                    "hidden facet is not linked to the visible facet",
+                ));
+            };
+            *slot = new_key;
+        }
+
+        // Link the new facets to each other through the ridges containing the eye point.
+        let mut ridges: HashMap<Vec<usize>, Vec<(usize, usize)>> = HashMap::new();
+        for (n, (_, i, vertices, _)) in new_facets.iter().enumerate() {
+            for j in (0..vertices.len()).filter(|j| j != i) {
+                let mut ridge: Vec<usize> = vertices
+                    .iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != j)
+                    .map(|(_, v)| *v)
+                    .collect();
+                ridge.sort_unstable();
+                ridges.entry(ridge).or_default().push((n, j));
+            }
+        }
+
+        for sharing in ridges.into_values() {
+            let [(n, j), (m, k)] = sharing[..] else {
+                return Err(ErrorKind::RoundOffError(
+                    "ridge should be shared by exactly two facets",
+                ));
+            };
+            new_facets[n].3[j] = new_keys[m];
+            new_facets[m].3[k] = new_keys[n];
+        }
+
+        for (new_key, (_, _, vertices, neighbors)) in new_keys.iter().zip(new_facets) {
+            self.facets.insert(
+                *new_key,
+                Facet {
+                    plane: orientation.plane(&vertices),
+                    vertices,
+                    neighbors,
+                    outside_points: Vec::new(),
+                },
+            );
+        }
+
+        Ok(new_keys)
+    }
+}
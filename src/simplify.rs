@@ -0,0 +1,141 @@
+//! Simplification of convex hulls into fewer vertices.
+
+use glam::DVec3;
+
+use std::collections::HashMap;
+
+use crate::collision::project;
+use crate::minkowski::hull_vertices;
+use crate::{ConvexHull, ErrorKind};
+
+/// The relative distance below which a simplified convex hull is considered exact.
+const SIMPLIFY_TOLERANCE: f64 = 1e-12;
+
+impl ConvexHull {
+    /// Simplifies the convex hull into one with at most `max_vertices` vertices
+    /// that contains every point of the original convex hull.
+    ///
+    /// The simplified convex hull is the intersection of some of the half-spaces bounding the original
+    /// convex hull. It starts from the bounding box of the convex hull, or a tetrahedron around it
+    /// for fewer than 8 vertices, and the face plane that cuts off the most of the vertex furthest
+    /// from the convex hull is added one at a time. Of the intersections within the vertex budget,
+    /// the one closest to the original convex hull is returned.
+    ///
+    /// ## Errors
+    /// If `max_vertices` is less than 4, or if computing the intersection of the half-spaces fails.
+    pub fn simplify(&self, max_vertices: usize) -> Result<Self, ErrorKind> {
+        if max_vertices < 4 {
+            return Err(ErrorKind::Degenerated);
+        }
+        self.simplify_with_planes(Some(max_vertices), 0.0)
+    }
+
+    /// Simplifies the convex hull into one that contains every point of the original convex hull,
+    /// and has no point further than `max_distance` from it.
+    ///
+    /// See [`ConvexHull::simplify`] for how face planes are chosen. Planes are added
+    /// until the vertex furthest from the original convex hull is close enough.
+    ///
+    /// ## Errors
+    /// If computing the intersection of the half-spaces fails.
+    pub fn simplify_to_error(&self, max_distance: f64) -> Result<Self, ErrorKind> {
+        self.simplify_with_planes(None, max_distance)
+    }
+
+    fn simplify_with_planes(
+        &self,
+        max_vertices: Option<usize>,
+        max_distance: f64,
+    ) -> Result<Self, ErrorKind> {
+        let vertices = hull_vertices(self);
+        let center = vertices.iter().sum::<DVec3>() / vertices.len() as f64;
+        let scale = vertices
+            .iter()
+            .map(|p| (*p - center).length())
+            .fold(0.0, f64::max);
+        let tolerance = max_distance.max(SIMPLIFY_TOLERANCE * scale);
+
+        // The planes are moved to touch the convex hull, as the offsets of thin faces are inaccurate,
+        // and then slightly further out, so that round-off errors cannot leave a point outside.
+        let supporting_plane = |normal: DVec3| {
+            let offset = project(&vertices, normal).1;
+            (normal, offset + SIMPLIFY_TOLERANCE * scale)
+        };
+        let face_planes: Vec<(DVec3, f64)> = self
+            .faces()
+            .filter_map(|(_, face)| face.normal.try_normalize())
+            .map(supporting_plane)
+            .collect();
+
+        // Start from the bounding box, or a tetrahedron if the box has too many vertices.
+        let initial_normals = if max_vertices.is_none_or(|max| max >= 8) {
+            vec![
+                DVec3::X,
+                DVec3::NEG_X,
+                DVec3::Y,
+                DVec3::NEG_Y,
+                DVec3::Z,
+                DVec3::NEG_Z,
+            ]
+        } else {
+            [
+                DVec3::new(1.0, 1.0, 1.0),
+                DVec3::new(1.0, -1.0, -1.0),
+                DVec3::new(-1.0, 1.0, -1.0),
+                DVec3::new(-1.0, -1.0, 1.0),
+            ]
+            .map(DVec3::normalize)
+            .to_vec()
+        };
+        let mut planes: Vec<(DVec3, f64)> =
+            initial_normals.into_iter().map(supporting_plane).collect();
+        let mut current = ConvexHull::from_half_spaces(&planes, center)?;
+
+        // The closest convex hull within the vertex budget, along with its distance.
+        let mut best: Option<(f64, ConvexHull)> = None;
+
+        // Most vertices are kept when a plane is added, so their distances are only computed once.
+        let mut distances = HashMap::new();
+
+        loop {
+            let current_vertices = hull_vertices(&current);
+            let (distance, furthest) = current_vertices
+                .iter()
+                .map(|p| {
+                    let distance = *distances
+                        .entry(p.to_array().map(f64::to_bits))
+                        .or_insert_with(|| self.distance_to_point(*p).0);
+                    (distance, *p)
+                })
+                .max_by(|(d1, _), (d2, _)| d1.total_cmp(d2))
+                .unwrap();
+
+            // Cutting off a vertex can add more vertices than it removes, until the initial planes
+            // become redundant, so the budget is only checked for the result.
+            let num_vertices = current_vertices.len();
+            let is_within_budget = max_vertices.is_none_or(|max| num_vertices <= max);
+            if is_within_budget && best.as_ref().is_none_or(|(best, _)| distance < *best) {
+                best = Some((distance, current.clone()));
+            }
+            if distance <= tolerance || max_vertices.is_some_and(|max| num_vertices > 2 * max) {
+                break;
+            }
+
+            // Without a plane cutting off the furthest vertex, the half-spaces cannot get any closer.
+            let Some(plane) = face_planes
+                .iter()
+                .max_by(|(n1, d1), (n2, d2)| {
+                    (n1.dot(furthest) - d1).total_cmp(&(n2.dot(furthest) - d2))
+                })
+                .filter(|(normal, offset)| normal.dot(furthest) > *offset)
+                .copied()
+            else {
+                break;
+            };
+            planes.push(plane);
+            current = ConvexHull::from_half_spaces(&planes, center)?;
+        }
+
+        Ok(best.map_or(current, |(_, best)| best))
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use glam::{DAffine3, DQuat, DVec2};
 
 #[test]
 fn four_points_coincident() {
@@ -405,3 +406,1314 @@ fn test_chull_errors() {
         ErrorKind::DegenerateInput(DegenerateInput::Coplanar)
     );
 }
+
+#[test]
+fn remove_points_cube_corner_test() {
+    let points: Vec<_> = (0..8)
+        .map(|i| DVec3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64) * 2.0)
+        .collect();
+    let mut cube = ConvexHull::try_new(&points, None).unwrap();
+    let corner = cube.points.iter().position(|p| *p == DVec3::ZERO).unwrap();
+
+    cube.remove_points(&[corner]).unwrap();
+
+    assert_eq!(cube.points.len(), 7);
+    assert!(!cube.points.contains(&DVec3::ZERO));
+    let volume = cube.volume();
+    assert!((volume - (8.0 - 8.0 / 6.0)).abs() < 1e-10, "got {volume}");
+}
+
+#[test]
+fn remove_points_matches_rebuild_test() {
+    let mut hull = ConvexHull::try_new(&sphere_points(10), None).unwrap();
+    let removed = [0, 3, 7, 20];
+    let remaining: Vec<_> = hull
+        .points
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !removed.contains(i))
+        .map(|(_, p)| *p)
+        .collect();
+
+    hull.remove_points(&removed).unwrap();
+    let rebuilt = ConvexHull::try_new(&remaining, None).unwrap();
+
+    assert_eq!(hull.points.len(), rebuilt.points.len());
+    assert!((hull.volume() - rebuilt.volume()).abs() < 1e-10);
+}
+
+#[test]
+fn retained_points_test() {
+    let mut points: Vec<_> = (0..8)
+        .map(|i| DVec3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+        .collect();
+    points.push(DVec3::splat(0.5));
+
+    let mut hull = ConvexHull::try_new_retained(&points, None).unwrap();
+    assert!(hull.retains_points());
+    assert_eq!(hull.points, points);
+
+    let kinds = hull.classify_points(0.0);
+    assert!(kinds[..8].iter().all(|kind| *kind == PointKind::Vertex));
+    assert_eq!(kinds[8], PointKind::Interior);
+    assert_eq!(hull.classify_points(0.6)[8], PointKind::Coplanar);
+
+    // A point on a face is kept, but does not become a vertex.
+    points.push(DVec3::new(0.5, 0.5, 1.0));
+    hull.add_points(&mut vec![points[9]]).unwrap();
+    assert_eq!(hull.classify_points(0.0)[9], PointKind::Coplanar);
+
+    // The interior points survive both growing and shrinking the hull.
+    hull.add_points(&mut vec![DVec3::new(0.5, 0.5, 3.0)])
+        .unwrap();
+    assert_eq!(hull.points.len(), 11);
+    assert_eq!(hull.classify_points(0.0)[9], PointKind::Interior);
+
+    hull.remove_points(&[10]).unwrap();
+    assert_eq!(hull.points, points);
+    assert_eq!(hull.classify_points(0.0)[9], PointKind::Coplanar);
+}
+
+#[test]
+fn insert_point_test() {
+    let points = sphere_points(10);
+    let mut hull = ConvexHull::try_new(&points, None).unwrap();
+    let num_points = hull.points.len();
+
+    assert_eq!(
+        hull.insert_point(DVec3::ZERO).unwrap(),
+        InsertResult::Inside
+    );
+    assert_eq!(hull.points.len(), num_points);
+
+    let face_keys_before: BTreeSet<usize> = hull.faces().map(|(key, _)| key).collect();
+    let InsertResult::Inserted {
+        index,
+        added_faces,
+        removed_faces,
+    } = hull.insert_point(DVec3::new(0.0, 0.0, 2.0)).unwrap()
+    else {
+        panic!("the point should be inserted");
+    };
+
+    assert_eq!(hull.points[index], DVec3::new(0.0, 0.0, 2.0));
+    assert!(added_faces
+        .iter()
+        .all(|key| !face_keys_before.contains(key)));
+    assert!(added_faces
+        .iter()
+        .all(|key| hull.face(*key).unwrap().indices.contains(&index)));
+    assert!(removed_faces
+        .iter()
+        .all(|key| face_keys_before.contains(key) && hull.face(*key).is_none()));
+
+    let mut expected_points = points.clone();
+    expected_points.push(DVec3::new(0.0, 0.0, 2.0));
+    let expected = ConvexHull::try_new(&expected_points, None).unwrap();
+    assert_eq!(hull.points.len(), expected.points.len());
+    assert!(hull
+        .classify_points(0.0)
+        .iter()
+        .all(|kind| *kind == PointKind::Vertex));
+    assert!((hull.volume() - expected.volume()).abs() < 1e-10);
+}
+
+#[test]
+fn merge_test() {
+    let cube: Vec<_> = (0..8)
+        .map(|i| DVec3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+        .collect();
+    let shifted: Vec<_> = cube
+        .iter()
+        .map(|p| *p + DVec3::new(0.5, 0.5, 2.0))
+        .collect();
+    let sphere: Vec<_> = sphere_points(10).iter().map(|p| *p * 0.6 + 0.5).collect();
+
+    let hulls = [
+        ConvexHull::try_new(&cube, None).unwrap(),
+        ConvexHull::try_new(&shifted, None).unwrap(),
+        ConvexHull::try_new(&sphere, None).unwrap(),
+    ];
+    let merged = ConvexHull::merge(&hulls.iter().collect::<Vec<_>>()).unwrap();
+
+    let all_points: Vec<_> = cube.into_iter().chain(shifted).chain(sphere).collect();
+    let expected = ConvexHull::try_new(&all_points, None).unwrap();
+
+    let sorted = |points: &[DVec3]| {
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.to_array().partial_cmp(&b.to_array()).unwrap());
+        points
+    };
+    assert_eq!(sorted(&merged.points), sorted(&expected.points));
+    assert!((merged.volume() - expected.volume()).abs() < 1e-10);
+
+    assert_eq!(ConvexHull::merge(&[]).unwrap_err(), ErrorKind::Empty);
+}
+
+#[cfg(test)]
+fn box_points(min: DVec3, max: DVec3) -> Vec<DVec3> {
+    (0..8)
+        .map(|i| {
+            DVec3::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn minkowski_sum_boxes_test() {
+    let a = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::ONE), None).unwrap();
+    let b = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::new(2.0, 3.0, 4.0)), None).unwrap();
+
+    let sum = ConvexHull::minkowski_sum(&a, &b).unwrap();
+    for corner in box_points(DVec3::ZERO, DVec3::new(3.0, 4.0, 5.0)) {
+        assert!(sum.points.contains(&corner), "{corner} should be a vertex");
+    }
+    assert!((sum.volume() - 3.0 * 4.0 * 5.0).abs() < 1e-10);
+
+    let difference = ConvexHull::minkowski_difference(&a, &a).unwrap();
+    for corner in box_points(DVec3::NEG_ONE, DVec3::ONE) {
+        assert!(
+            difference.points.contains(&corner),
+            "{corner} should be a vertex"
+        );
+    }
+    assert!((difference.volume() - 8.0).abs() < 1e-10);
+}
+
+#[test]
+fn minkowski_sum_matches_pairwise_test() {
+    let sphere = ConvexHull::try_new(&sphere_points(8), None).unwrap();
+    let tetrahedron = ConvexHull::try_new(
+        &[
+            DVec3::new(0.3, 0.1, -0.2),
+            DVec3::new(1.0, 0.0, 0.0),
+            DVec3::new(0.2, 0.9, 0.1),
+            DVec3::new(0.1, 0.2, 1.3),
+        ],
+        None,
+    )
+    .unwrap();
+
+    for (a, b) in [(&sphere, &tetrahedron), (&tetrahedron, &sphere)] {
+        let pairwise: Vec<_> = a
+            .points
+            .iter()
+            .flat_map(|p| b.points.iter().map(move |q| *p - *q))
+            .collect();
+        let expected = ConvexHull::try_new(&pairwise, None).unwrap();
+        let difference = ConvexHull::minkowski_difference(a, b).unwrap();
+
+        // Near-duplicate input points make the exact vertex count vary, but not the shape.
+        for direction in sphere_points(6) {
+            assert!(
+                (difference.support_point(direction).dot(direction)
+                    - expected.support_point(direction).dot(direction))
+                .abs()
+                    < 1e-10
+            );
+        }
+        assert!((difference.volume() - expected.volume()).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn intersection_test() {
+    let a = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+    let b = ConvexHull::try_new(&box_points(DVec3::ONE, DVec3::splat(3.0)), None).unwrap();
+    let c = ConvexHull::try_new(&box_points(DVec3::splat(2.0), DVec3::splat(3.0)), None).unwrap();
+    let d = ConvexHull::try_new(&box_points(DVec3::splat(5.0), DVec3::splat(6.0)), None).unwrap();
+
+    let overlap = ConvexHull::intersection(&a, &b).unwrap().unwrap();
+    for corner in box_points(DVec3::ONE, DVec3::splat(2.0)) {
+        assert!(
+            overlap.points.contains(&corner),
+            "{corner} should be a vertex"
+        );
+    }
+    assert!((overlap.volume() - 1.0).abs() < 1e-10);
+
+    // Touching and disjoint hulls have no overlap volume.
+    assert!(ConvexHull::intersection(&a, &c).unwrap().is_none());
+    assert!(ConvexHull::intersection(&a, &d).unwrap().is_none());
+
+    // A hull contained in the other is its own intersection.
+    let sphere = ConvexHull::try_new(&sphere_points(10), None).unwrap();
+    let big_box =
+        ConvexHull::try_new(&box_points(DVec3::splat(-2.0), DVec3::splat(2.0)), None).unwrap();
+    let inner = ConvexHull::intersection(&big_box, &sphere)
+        .unwrap()
+        .unwrap();
+    assert!((inner.volume() - sphere.clone().volume()).abs() < 1e-10);
+
+    // Cutting the sphere in half along its equator.
+    let half_box = ConvexHull::try_new(
+        &box_points(DVec3::new(-2.0, -2.0, 0.0), DVec3::splat(2.0)),
+        None,
+    )
+    .unwrap();
+    let half = ConvexHull::intersection(&sphere, &half_box)
+        .unwrap()
+        .unwrap();
+    assert!(half.points.iter().all(|p| p.z > -1e-12));
+    assert!((half.volume() - sphere.volume() / 2.0).abs() < 1e-10);
+}
+
+#[test]
+fn from_half_spaces_test() {
+    let mut half_spaces = vec![
+        (DVec3::X, 1.0),
+        (DVec3::NEG_X, 1.0),
+        (DVec3::Y, 2.0),
+        (DVec3::NEG_Y, 2.0),
+        (DVec3::Z, 3.0),
+        (DVec3::NEG_Z, 3.0),
+        // A redundant half-space touching a corner.
+        (DVec3::ONE, 6.0),
+    ];
+    let c_hull = ConvexHull::from_half_spaces(&half_spaces, DVec3::new(0.5, 0.0, 0.0)).unwrap();
+    let (points, _) = c_hull.clone().vertices_indices();
+    assert_eq!(points.len(), 8);
+    for corner in box_points(DVec3::new(-1.0, -2.0, -3.0), DVec3::new(1.0, 2.0, 3.0)) {
+        assert!(points.iter().any(|p| p.distance(corner) < 1e-12));
+    }
+    assert!((c_hull.volume() - 48.0).abs() < 1e-10);
+
+    // The interior point must be strictly inside.
+    assert_eq!(
+        ConvexHull::from_half_spaces(&half_spaces, DVec3::new(1.0, 0.0, 0.0)).unwrap_err(),
+        ErrorKind::EmptyIntersection
+    );
+
+    half_spaces.remove(5);
+    assert_eq!(
+        ConvexHull::from_half_spaces(&half_spaces, DVec3::ZERO).unwrap_err(),
+        ErrorKind::Unbounded
+    );
+    assert_eq!(
+        ConvexHull::from_half_spaces(&half_spaces[..3], DVec3::ZERO).unwrap_err(),
+        ErrorKind::Unbounded
+    );
+
+    // The face planes of a convex hull give back the same convex hull. Thin faces between
+    // near-duplicate points are skipped, as their planes are too inaccurate.
+    let sphere = ConvexHull::try_new(&sphere_points(10), None).unwrap();
+    let planes: Vec<_> = sphere
+        .faces()
+        .filter(|(_, face)| face.normal.length() > 1e-6)
+        .map(|(_, face)| {
+            let normal = face.normal.normalize();
+            (normal, normal.dot(sphere.points[face.indices[0]]))
+        })
+        .collect();
+    let from_planes = ConvexHull::from_half_spaces(&planes, DVec3::splat(0.1)).unwrap();
+    assert!((from_planes.volume() - sphere.volume()).abs() < 1e-8);
+}
+
+#[test]
+fn contact_test() {
+    let a = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+    let b = ConvexHull::try_new(
+        &box_points(DVec3::new(1.5, 0.2, 0.1), DVec3::new(3.5, 2.2, 2.1)),
+        None,
+    )
+    .unwrap();
+    let contact = a.contact(&b).unwrap();
+    assert!((contact.depth - 0.5).abs() < 1e-12);
+    assert!(contact.normal.abs_diff_eq(DVec3::X, 1e-12));
+
+    // Swapping the convex hulls flips the normal.
+    let contact = b.contact(&a).unwrap();
+    assert!((contact.depth - 0.5).abs() < 1e-12);
+    assert!(contact.normal.abs_diff_eq(DVec3::NEG_X, 1e-12));
+
+    // Touching and disjoint boxes do not overlap.
+    let c = ConvexHull::try_new(&box_points(DVec3::splat(2.0), DVec3::splat(3.0)), None).unwrap();
+    let d = ConvexHull::try_new(&box_points(DVec3::splat(5.0), DVec3::splat(6.0)), None).unwrap();
+    assert!(a.overlaps(&b));
+    assert!(!a.overlaps(&c));
+    assert!(!a.overlaps(&d));
+
+    // Two cubes rotated so that a vertical edge of one crosses a horizontal edge of the other,
+    // where only the cross product of the edges gives the contact normal.
+    let cube = box_points(DVec3::NEG_ONE, DVec3::ONE);
+    let offset = 2.0 * std::f64::consts::SQRT_2 - 0.1;
+    let rotated_z = DQuat::from_rotation_z(std::f64::consts::FRAC_PI_4);
+    let rotated_y = DQuat::from_rotation_y(std::f64::consts::FRAC_PI_4);
+    let a = ConvexHull::try_new(
+        &cube.iter().map(|p| rotated_z * *p).collect::<Vec<_>>(),
+        None,
+    )
+    .unwrap();
+    let b = ConvexHull::try_new(
+        &cube
+            .iter()
+            .map(|p| rotated_y * *p + DVec3::X * offset)
+            .collect::<Vec<_>>(),
+        None,
+    )
+    .unwrap();
+    let contact = a.contact(&b).unwrap();
+    assert!((contact.depth - 0.1).abs() < 1e-12);
+    assert!(contact.normal.abs_diff_eq(DVec3::X, 1e-12));
+
+    // Moving the second cube a bit further separates them.
+    let b = ConvexHull::try_new(
+        &cube
+            .iter()
+            .map(|p| rotated_y * *p + DVec3::X * (offset + 0.2))
+            .collect::<Vec<_>>(),
+        None,
+    )
+    .unwrap();
+    assert!(a.contact(&b).is_none());
+}
+
+#[test]
+fn distance_test() {
+    let a = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+
+    // Face to face.
+    let b = ConvexHull::try_new(
+        &box_points(DVec3::new(3.0, 0.5, 0.5), DVec3::new(4.0, 1.5, 1.5)),
+        None,
+    )
+    .unwrap();
+    let (distance, point_a, point_b) = ConvexHull::distance(&a, &b);
+    assert!((distance - 1.0).abs() < 1e-12);
+    assert!((point_a.x - 2.0).abs() < 1e-12);
+    assert!((point_b.x - 3.0).abs() < 1e-12);
+    assert!((point_a.distance(point_b) - distance).abs() < 1e-12);
+
+    // Vertex to vertex.
+    let c = ConvexHull::try_new(&box_points(DVec3::splat(3.0), DVec3::splat(4.0)), None).unwrap();
+    let (distance, point_a, point_c) = ConvexHull::distance(&a, &c);
+    assert!((distance - 3.0f64.sqrt()).abs() < 1e-12);
+    assert!(point_a.abs_diff_eq(DVec3::splat(2.0), 1e-12));
+    assert!(point_c.abs_diff_eq(DVec3::splat(3.0), 1e-12));
+
+    // Overlapping convex hulls have no distance.
+    let d = ConvexHull::try_new(&box_points(DVec3::ONE, DVec3::splat(3.0)), None).unwrap();
+    let (distance, point_a, point_d) = ConvexHull::distance(&a, &d);
+    assert_eq!(distance, 0.0);
+    assert!(point_a.abs_diff_eq(point_d, 1e-12));
+
+    // A transform gives the same result as transforming the points.
+    let sphere = ConvexHull::try_new(&sphere_points(10), None).unwrap();
+    let transform = DAffine3::from_scale_rotation_translation(
+        DVec3::new(1.0, 2.0, 0.5),
+        DQuat::from_rotation_y(0.3),
+        DVec3::new(-2.0, 5.0, 1.0),
+    );
+    let transformed = ConvexHull::try_new(
+        &sphere
+            .points
+            .iter()
+            .map(|p| transform.transform_point3(*p))
+            .collect::<Vec<_>>(),
+        None,
+    )
+    .unwrap();
+    let (distance, point_a, point_sphere) =
+        ConvexHull::distance_with_transform(&a, &sphere, &transform);
+    let (expected, expected_a, expected_sphere) = ConvexHull::distance(&a, &transformed);
+    assert!(distance > 1.0);
+    assert!((distance - expected).abs() < 1e-9);
+    assert!(point_a.abs_diff_eq(expected_a, 1e-6));
+    assert!(point_sphere.abs_diff_eq(expected_sphere, 1e-6));
+}
+
+#[test]
+fn transform_test() {
+    let sphere = ConvexHull::try_new(&sphere_points(10), None).unwrap();
+    let volume = sphere.clone().volume();
+
+    // A non-uniform scale that also mirrors the convex hull.
+    let transform = DAffine3::from_scale_rotation_translation(
+        DVec3::new(2.0, -0.5, 3.0),
+        DQuat::from_rotation_x(0.7),
+        DVec3::new(1.0, 2.0, 3.0),
+    );
+    let transformed = sphere.transformed(&transform).unwrap();
+
+    for (_, face) in transformed.faces() {
+        let points = [0, 1, 2].map(|i| transformed.points[face.indices[i]]);
+        let expected = triangle_normal(points);
+        assert!(face.normal.abs_diff_eq(expected, 1e-12));
+        assert!((face.distance_from_origin - expected.dot(points[0])).abs() < 1e-12);
+        for point in &transformed.points {
+            assert!(face.normal.dot(*point) <= face.distance_from_origin + 1e-12);
+        }
+    }
+    assert!((transformed.volume() - 3.0 * volume).abs() < 1e-10);
+
+    let mut c_hull = sphere.clone();
+    c_hull
+        .transform(&DMat4::from_translation(DVec3::X))
+        .unwrap();
+    assert!(
+        (c_hull.support_point(DVec3::X).x - sphere.support_point(DVec3::X).x - 1.0).abs() < 1e-12
+    );
+
+    // Singular and projective matrices are rejected.
+    let singular = DAffine3::from_scale(DVec3::new(1.0, 0.0, 1.0));
+    assert_eq!(
+        sphere.transformed(&singular).unwrap_err(),
+        ErrorKind::InvalidTransform
+    );
+    let projective = DMat4::perspective_rh(1.0, 1.0, 0.1, 10.0);
+    assert_eq!(
+        c_hull.transform(&projective).unwrap_err(),
+        ErrorKind::InvalidTransform
+    );
+}
+
+#[test]
+fn split_test() {
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+    let (behind, in_front) = cube.split(DVec3::X, 0.5);
+    let (behind, in_front) = (behind.unwrap(), in_front.unwrap());
+    for corner in box_points(DVec3::ZERO, DVec3::new(0.5, 2.0, 2.0)) {
+        assert!(
+            behind.points.contains(&corner),
+            "{corner} should be a vertex"
+        );
+    }
+    for corner in box_points(DVec3::new(0.5, 0.0, 0.0), DVec3::splat(2.0)) {
+        assert!(
+            in_front.points.contains(&corner),
+            "{corner} should be a vertex"
+        );
+    }
+    assert!((behind.volume() - 2.0).abs() < 1e-12);
+    assert!((in_front.volume() - 6.0).abs() < 1e-12);
+
+    // Planes that do not cut through the convex hull leave one side empty.
+    let (behind, in_front) = cube.split(DVec3::X, 2.0);
+    assert!((behind.unwrap().volume() - 8.0).abs() < 1e-12);
+    assert!(in_front.is_none());
+    assert!(cube.clip(DVec3::NEG_Z, -3.0).is_none());
+
+    // Splitting a sphere with a tilted plane and splitting the pieces again keeps the volume.
+    let sphere = ConvexHull::try_new(&sphere_points(10), None).unwrap();
+    let volume = sphere.clone().volume();
+    let normal = DVec3::new(1.0, 2.0, -0.5);
+    let (behind, in_front) = sphere.split(normal, 0.3);
+    let (behind, in_front) = (behind.unwrap(), in_front.unwrap());
+    for point in &behind.points {
+        assert!(normal.dot(*point) <= 0.3 + 1e-12);
+    }
+    for point in &in_front.points {
+        assert!(normal.dot(*point) >= 0.3 - 1e-12);
+    }
+    let pieces = [behind.split(DVec3::Y, 0.1), in_front.split(DVec3::Y, 0.1)];
+    let total: f64 = pieces
+        .into_iter()
+        .flat_map(|(a, b)| [a, b])
+        .map(|piece| piece.unwrap().volume())
+        .sum();
+    assert!((total - volume).abs() < 1e-10);
+}
+
+#[test]
+fn cross_section_test() {
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+
+    // A horizontal slice is a square, counterclockwise seen from above.
+    let square = cube.cross_section(DVec3::Z, 0.5);
+    assert_eq!(square.len(), 4);
+    for (i, point) in square.iter().enumerate() {
+        assert!((point.z - 0.5).abs() < 1e-12);
+        let next = square[(i + 1) % square.len()];
+        let after_next = square[(i + 2) % square.len()];
+        assert!((next - *point).cross(after_next - next).z > 0.0);
+    }
+
+    // A diagonal slice through the center is a regular hexagon.
+    let hexagon = cube.cross_section(DVec3::ONE, 3.0);
+    assert_eq!(hexagon.len(), 6);
+    for point in &hexagon {
+        assert!((point.distance(DVec3::ONE) - 2.0f64.sqrt()).abs() < 1e-12);
+    }
+
+    // The top face, a touching corner, and a plane missing the cube.
+    assert_eq!(cube.cross_section(DVec3::Z, 2.0).len(), 4);
+    assert_eq!(cube.cross_section(DVec3::ONE, 6.0), vec![DVec3::splat(2.0)]);
+    assert!(cube.cross_section(DVec3::Z, 3.0).is_empty());
+}
+
+#[test]
+fn silhouette_test() {
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+
+    // Seen from above, the silhouette is the top face, counterclockwise.
+    let outline: Vec<DVec3> = cube
+        .silhouette(DVec3::NEG_Z)
+        .into_iter()
+        .map(|i| cube.points[i])
+        .collect();
+    assert_eq!(outline.len(), 4);
+    let area: f64 = (0..4)
+        .map(|i| {
+            outline[i]
+                .truncate()
+                .perp_dot(outline[(i + 1) % 4].truncate())
+        })
+        .sum();
+    assert_eq!(area / 2.0, 4.0);
+
+    // Along the diagonal, the silhouette is a hexagon.
+    assert_eq!(cube.silhouette(DVec3::ONE).len(), 6);
+
+    // An eye point above the top face only sees the top face, and far from a corner
+    // it sees three faces.
+    assert_eq!(
+        cube.silhouette_from_point(DVec3::new(1.0, 1.0, 10.0)).len(),
+        4
+    );
+    let hexagon = cube.silhouette_from_point(DVec3::splat(10.0));
+    assert_eq!(hexagon.len(), 6);
+    assert!(!hexagon.iter().any(|i| cube.points[*i] == DVec3::splat(2.0)));
+    assert!(!hexagon.iter().any(|i| cube.points[*i] == DVec3::ZERO));
+
+    // No silhouette from inside.
+    assert!(cube.silhouette_from_point(DVec3::ONE).is_empty());
+
+    // The silhouette of a sphere seen from a point is a closed loop of its vertices.
+    let sphere = ConvexHull::try_new(&sphere_points(10), None).unwrap();
+    let horizon = sphere.silhouette_from_point(DVec3::new(0.0, 0.0, 3.0));
+    assert!(horizon.len() >= 3);
+    for i in &horizon {
+        assert!(sphere.points[*i].z > 0.0);
+    }
+}
+
+#[test]
+fn project_onto_plane_test() {
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+    let polygon_area = |polygon: &[DVec2]| {
+        (0..polygon.len())
+            .map(|i| polygon[i].perp_dot(polygon[(i + 1) % polygon.len()]))
+            .sum::<f64>()
+            / 2.0
+    };
+
+    let square = cube.project_onto_plane(DVec3::Z);
+    assert_eq!(square.len(), 4);
+    assert!((polygon_area(&square) - 4.0).abs() < 1e-12);
+
+    // The projection along the diagonal is a hexagon, with the area of the three faces facing it.
+    let hexagon = cube.project_onto_plane(DVec3::ONE);
+    assert_eq!(hexagon.len(), 6);
+    assert!((polygon_area(&hexagon) - 4.0 * 3.0f64.sqrt()).abs() < 1e-12);
+
+    // The coordinates are in the basis of the plane.
+    let (u, v) = plane_basis(DVec3::ONE);
+    assert!(u.cross(v).abs_diff_eq(DVec3::ONE.normalize(), 1e-12));
+    for point in &hexagon {
+        assert!(cube
+            .points
+            .iter()
+            .any(|p| DVec2::new(p.dot(u), p.dot(v)) == *point));
+    }
+}
+
+#[test]
+fn bounding_volumes_test() {
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+    assert_eq!(cube.aabb(), (DVec3::ZERO, DVec3::splat(2.0)));
+    let (center, radius) = cube.bounding_sphere();
+    assert!(center.abs_diff_eq(DVec3::ONE, 1e-12));
+    assert!((radius - 3.0f64.sqrt()).abs() < 1e-12);
+
+    // The bounding sphere of a regular tetrahedron is its circumsphere.
+    let tetrahedron = ConvexHull::try_new(
+        &[
+            DVec3::new(1.0, 1.0, 1.0),
+            DVec3::new(1.0, -1.0, -1.0),
+            DVec3::new(-1.0, 1.0, -1.0),
+            DVec3::new(-1.0, -1.0, 1.0),
+        ],
+        None,
+    )
+    .unwrap();
+    let (center, radius) = tetrahedron.bounding_sphere();
+    assert!(center.abs_diff_eq(DVec3::ZERO, 1e-12));
+    assert!((radius - 3.0f64.sqrt()).abs() < 1e-12);
+
+    // Two far apart points decide the bounding sphere of an elongated convex hull,
+    // unlike the sphere around the centroid.
+    let mut points = sphere_points(6);
+    points.push(DVec3::new(10.0, 0.0, 0.0));
+    points.push(DVec3::new(-4.0, 0.0, 0.0));
+    let elongated = ConvexHull::try_new(&points, None).unwrap();
+    let (center, radius) = elongated.bounding_sphere();
+    assert!(center.abs_diff_eq(DVec3::new(3.0, 0.0, 0.0), 1e-12));
+    assert!((radius - 7.0).abs() < 1e-12);
+
+    let (min, max) = elongated.aabb();
+    let expected_min = points.iter().fold(DVec3::INFINITY, |min, p| min.min(*p));
+    let expected_max = points
+        .iter()
+        .fold(DVec3::NEG_INFINITY, |max, p| max.max(*p));
+    assert_eq!((min, max), (expected_min, expected_max));
+}
+
+#[test]
+fn oriented_bounding_box_test() {
+    use rand::prelude::{Distribution, SeedableRng};
+
+    let rotation = DQuat::from_euler(glam::EulerRot::XYZ, 0.3, -0.7, 1.1);
+    let translation = DVec3::new(5.0, -2.0, 1.0);
+    let points: Vec<DVec3> = box_points(DVec3::new(-1.0, -2.0, -3.0), DVec3::new(1.0, 2.0, 3.0))
+        .into_iter()
+        .map(|p| rotation * p + translation)
+        .collect();
+    let c_hull = ConvexHull::try_new(&points, None).unwrap();
+
+    let (center, axes, half_extents) = c_hull.oriented_bounding_box();
+    assert!(center.abs_diff_eq(translation, 1e-9));
+    assert!((axes.determinant() - 1.0).abs() < 1e-9);
+    let mut extents = half_extents.to_array();
+    extents.sort_by(f64::total_cmp);
+    assert!(DVec3::from_array(extents).abs_diff_eq(DVec3::new(1.0, 2.0, 3.0), 1e-9));
+
+    // The box is much tighter than the axis-aligned one, and contains every point.
+    let (min, max) = c_hull.aabb();
+    assert!(8.0 * half_extents.element_product() < (max - min).element_product());
+    for point in &points {
+        let local = axes.transpose() * (*point - center);
+        assert!(local.abs().cmple(half_extents + 1e-9).all());
+    }
+
+    // The box is never larger than the axis-aligned one.
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let dist = rand::distr::StandardUniform;
+    for _ in 0..400 {
+        let points: Vec<DVec3> = (0..30)
+            .map(|_| {
+                DVec3::new(
+                    dist.sample(&mut rng),
+                    dist.sample(&mut rng),
+                    dist.sample(&mut rng),
+                )
+            })
+            .collect();
+        let c_hull = ConvexHull::try_new(&points, None).unwrap();
+        let (center, axes, half_extents) = c_hull.oriented_bounding_box();
+        let (min, max) = c_hull.aabb();
+        assert!(8.0 * half_extents.element_product() <= (max - min).element_product());
+        for point in &points {
+            let local = axes.transpose() * (*point - center);
+            assert!(local.abs().cmple(half_extents + 1e-9).all());
+        }
+    }
+}
+
+#[test]
+fn diameter_and_width_test() {
+    let c_hull =
+        ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::new(1.0, 2.0, 3.0)), None).unwrap();
+    let (diameter, [i, j]) = c_hull.diameter();
+    assert!((diameter - 14.0f64.sqrt()).abs() < 1e-12);
+    assert_eq!(
+        c_hull.points[i] + c_hull.points[j],
+        DVec3::new(1.0, 2.0, 3.0)
+    );
+
+    let (width, normal, pair) = c_hull.width();
+    assert!((width - 1.0).abs() < 1e-12);
+    assert!(normal.abs().abs_diff_eq(DVec3::X, 1e-12));
+    assert!(matches!(pair, AntipodalPair::FaceVertex { .. }));
+
+    // The width of a regular tetrahedron is between two opposite edges.
+    let tetrahedron = ConvexHull::try_new(
+        &[
+            DVec3::new(1.0, 1.0, 1.0),
+            DVec3::new(1.0, -1.0, -1.0),
+            DVec3::new(-1.0, 1.0, -1.0),
+            DVec3::new(-1.0, -1.0, 1.0),
+        ],
+        None,
+    )
+    .unwrap();
+    let (diameter, _) = tetrahedron.diameter();
+    assert!((diameter - 8.0f64.sqrt()).abs() < 1e-12);
+    let (width, normal, pair) = tetrahedron.width();
+    assert!((width - 2.0).abs() < 1e-12);
+    let AntipodalPair::EdgeEdge {
+        edges: [[a0, a1], [b0, b1]],
+    } = pair
+    else {
+        panic!("expected two edges, got {pair:?}");
+    };
+    let points = &tetrahedron.points;
+    assert!(((points[a0] - points[b0]).dot(normal).abs() - 2.0).abs() < 1e-12);
+    assert_eq!(
+        [a0, a1, b0, b1].into_iter().collect::<BTreeSet<_>>().len(),
+        4
+    );
+    assert!((points[a1] - points[a0]).dot(normal).abs() < 1e-12);
+    assert!((points[b1] - points[b0]).dot(normal).abs() < 1e-12);
+
+    // The width is the extent along its normal, and no face normal gives a smaller extent.
+    let sphere = ConvexHull::try_new(&sphere_points(20), None).unwrap();
+    let extent = |direction: DVec3| {
+        let distances = sphere.points.iter().map(|p| p.dot(direction));
+        distances.clone().fold(f64::MIN, f64::max) - distances.fold(f64::MAX, f64::min)
+    };
+    let (width, normal, _) = sphere.width();
+    assert!((width - extent(normal)).abs() < 1e-12);
+    for (_, face) in sphere.faces() {
+        assert!(width <= extent(face.normal.normalize()) + 1e-12);
+    }
+}
+
+#[test]
+fn simplify_test() {
+    let sphere = ConvexHull::try_new(&sphere_points(30), None).unwrap();
+    let (vertices, _) = sphere.clone().vertices_indices();
+    assert!(vertices.len() > 64);
+
+    let simplified = sphere.simplify(64).unwrap();
+    let (simplified_vertices, _) = simplified.clone().vertices_indices();
+    assert!(simplified_vertices.len() <= 64);
+    for point in &vertices {
+        assert!(simplified.distance_to_point(*point).0 < 1e-12);
+    }
+
+    // A more lenient error needs fewer vertices.
+    let coarse = sphere.simplify_to_error(0.2).unwrap();
+    let fine = sphere.simplify_to_error(0.05).unwrap();
+    for c_hull in [&coarse, &fine] {
+        for point in &vertices {
+            assert!(c_hull.distance_to_point(*point).0 < 1e-12);
+        }
+    }
+    for (c_hull, max_distance) in [(&coarse, 0.2), (&fine, 0.05)] {
+        for point in &c_hull.points {
+            assert!(sphere.distance_to_point(*point).0 <= max_distance + 1e-9);
+        }
+    }
+    assert!(coarse.points.len() < fine.points.len());
+
+    // A convex hull within the budget is kept as it is.
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+    let simplified = cube.simplify(8).unwrap();
+    assert!((simplified.volume() - 8.0).abs() < 1e-9);
+    assert_eq!(cube.simplify(3).unwrap_err(), ErrorKind::Degenerated);
+
+    // Small budgets start from a tetrahedron.
+    let tetrahedron = sphere.simplify(4).unwrap();
+    assert_eq!(tetrahedron.clone().vertices_indices().0.len(), 4);
+    for point in &vertices {
+        assert!(tetrahedron.distance_to_point(*point).0 < 1e-12);
+    }
+}
+
+#[test]
+fn offset_test() {
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::splat(2.0)), None).unwrap();
+    assert!((cube.offset(0.5).unwrap().volume() - 27.0).abs() < 1e-9);
+    let shrunk = cube.offset(-0.5).unwrap();
+    let (min, max) = shrunk.aabb();
+    assert!(min.abs_diff_eq(DVec3::splat(0.5), 1e-12));
+    assert!(max.abs_diff_eq(DVec3::splat(1.5), 1e-12));
+    assert!((shrunk.volume() - 1.0).abs() < 1e-9);
+    assert_eq!(cube.offset(-1.5).unwrap_err(), ErrorKind::EmptyIntersection);
+
+    // A long and thin wedge, where the centroid of the vertices is too close
+    // to the sloped face to be inside the shrunk wedge.
+    let wedge = ConvexHull::try_new(
+        &[
+            DVec3::new(0.0, 0.0, 0.0),
+            DVec3::new(10.0, 0.0, 0.0),
+            DVec3::new(0.0, 1.0, 0.0),
+            DVec3::new(0.0, 0.0, 10.0),
+            DVec3::new(10.0, 0.0, 10.0),
+            DVec3::new(0.0, 1.0, 10.0),
+        ],
+        None,
+    )
+    .unwrap();
+    let inradius = 5.0 / (11.0 + 101.0f64.sqrt()) * 2.0;
+    let shrunk = wedge.offset(-0.4).unwrap();
+    let scale = (inradius - 0.4) / inradius;
+    let expected = 5.0 * scale * scale * 9.2;
+    assert!((shrunk.volume() - expected).abs() < 1e-9);
+
+    // A long prism with a very thin right triangle as its cross-section, which only has room
+    // for a point deep inside along a narrow line.
+    let triangle = [
+        DVec2::new(0.0, 0.0),
+        DVec2::new(1.0, 0.0),
+        DVec2::new(0.0, 0.01),
+    ];
+    let prism = ConvexHull::try_new(
+        &[0.0, 100.0]
+            .iter()
+            .flat_map(|z| triangle.iter().map(move |p| p.extend(*z)))
+            .collect::<Vec<_>>(),
+        None,
+    )
+    .unwrap();
+    let inradius = (1.0 + 0.01 - 1.0001f64.sqrt()) / 2.0;
+    let shrunk = prism.offset(-0.9 * inradius).unwrap();
+    let expected = 0.005 * 0.1 * 0.1 * (100.0 - 1.8 * inradius);
+    assert!((shrunk.volume() - expected).abs() < 1e-9);
+    assert_eq!(
+        prism.offset(-1.1 * inradius).unwrap_err(),
+        ErrorKind::EmptyIntersection
+    );
+}
+
+#[test]
+fn decompose_test() {
+    // A convex mesh needs only one convex hull.
+    let cube = ConvexHull::try_new(&box_points(DVec3::ZERO, DVec3::ONE), None).unwrap();
+    let (vertices, indices) = cube.vertices_indices();
+    let triangles: Vec<[usize; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect();
+    let params = DecompositionParams {
+        resolution: 16,
+        ..Default::default()
+    };
+    let hulls = ConvexHull::decompose(&vertices, &triangles, &params).unwrap();
+    assert_eq!(hulls.len(), 1);
+    assert!((hulls[0].clone().volume() - 1.0).abs() < 1e-9);
+
+    // An L-shaped prism, with its bottom and top triangulated as a fan around the first corner.
+    let outline = [
+        DVec2::new(0.0, 0.0),
+        DVec2::new(2.0, 0.0),
+        DVec2::new(2.0, 1.0),
+        DVec2::new(1.0, 1.0),
+        DVec2::new(1.0, 2.0),
+        DVec2::new(0.0, 2.0),
+    ];
+    let vertices: Vec<DVec3> = [0.0, 1.0]
+        .iter()
+        .flat_map(|z| outline.iter().map(move |p| p.extend(*z)))
+        .collect();
+    let mut triangles = Vec::new();
+    for i in 0..6 {
+        let j = (i + 1) % 6;
+        triangles.push([i, j, j + 6]);
+        triangles.push([i, j + 6, i + 6]);
+    }
+    for i in 1..5 {
+        triangles.push([0, i + 1, i]);
+        triangles.push([6, i + 6, i + 7]);
+    }
+
+    let hulls = ConvexHull::decompose(&vertices, &triangles, &params).unwrap();
+    assert!(hulls.len() >= 2);
+    assert!(hulls.len() <= params.max_hulls);
+
+    let total_volume: f64 = hulls.iter().map(|h| h.clone().volume()).sum();
+    assert!((total_volume - 3.0).abs() < 0.2, "{total_volume}");
+
+    // Every vertex of the mesh is covered, and no convex hull pokes out of the convex hull of the mesh.
+    let mesh_hull = ConvexHull::try_new(&vertices, None).unwrap();
+    for vertex in &vertices {
+        assert!(hulls.iter().any(|h| h.distance_to_point(*vertex).0 < 1e-9));
+    }
+    for c_hull in &hulls {
+        assert!(c_hull.vertex_indices().len() <= params.max_vertices);
+        for vertex in c_hull.clone().vertices_indices().0 {
+            assert!(mesh_hull.distance_to_point(vertex).0 < 1e-9);
+        }
+    }
+
+    // The number of convex hulls is limited.
+    let single = DecompositionParams {
+        max_hulls: 1,
+        ..params
+    };
+    let hulls = ConvexHull::decompose(&vertices, &triangles, &single).unwrap();
+    assert_eq!(hulls.len(), 1);
+}
+
+#[test]
+fn primitives_test() {
+    let capsule_volume = std::f64::consts::PI * 4.0 + 4.0 / 3.0 * std::f64::consts::PI;
+    let spheres = [
+        Primitive::Sphere {
+            center: DVec3::new(-2.0, 0.0, 0.0),
+            radius: 1.0,
+        },
+        Primitive::Sphere {
+            center: DVec3::new(2.0, 0.0, 0.0),
+            radius: 1.0,
+        },
+    ];
+
+    // The tessellated convex hull is slightly inside of the exact one.
+    let c_hull = ConvexHull::try_from_primitives(&spheres, 3).unwrap();
+    let (min, max) = c_hull.aabb();
+    assert!(min.cmpge(DVec3::new(-3.0, -1.0, -1.0)).all());
+    assert!(max.cmple(DVec3::new(3.0, 1.0, 1.0)).all());
+    assert!(min.abs_diff_eq(DVec3::new(-3.0, -1.0, -1.0), 0.01));
+    assert!(max.abs_diff_eq(DVec3::new(3.0, 1.0, 1.0), 0.01));
+    let volume = c_hull.volume();
+    assert!(volume < capsule_volume && volume > 0.98 * capsule_volume);
+
+    // Mixed primitives.
+    let mixed = [
+        Primitive::Point(DVec3::new(0.0, 5.0, 0.0)),
+        Primitive::Capsule {
+            a: DVec3::ZERO,
+            b: DVec3::new(0.0, 0.0, 4.0),
+            radius: 0.5,
+        },
+        Primitive::Cuboid {
+            center: DVec3::new(3.0, 0.0, 0.0),
+            half_extents: DVec3::new(1.0, 0.5, 0.5),
+            rotation: DQuat::from_rotation_z(std::f64::consts::FRAC_PI_2),
+        },
+    ];
+    let c_hull = ConvexHull::try_from_primitives(&mixed, 1).unwrap();
+    let (min, max) = c_hull.aabb();
+    assert!(min.abs_diff_eq(DVec3::new(-0.5, -1.0, -0.5), 0.05));
+    assert!(max.abs_diff_eq(DVec3::new(3.5, 5.0, 4.5), 0.05));
+    assert_eq!(
+        ConvexHull::try_from_primitives(&[], 1).unwrap_err(),
+        ErrorKind::Empty
+    );
+
+    // Spheres with the same radius have an exact rounded convex hull.
+    let rounded = RoundedConvexHull::try_new(&spheres, 1).unwrap();
+    assert_eq!(rounded.core.len(), 2);
+    assert_eq!(rounded.radius, 1.0);
+    assert!(rounded
+        .support_point(DVec3::new(1.0, 1.0, 0.0))
+        .abs_diff_eq(
+            DVec3::new(
+                2.0 + std::f64::consts::FRAC_1_SQRT_2,
+                std::f64::consts::FRAC_1_SQRT_2,
+                0.0
+            ),
+            1e-12
+        ));
+    assert!(rounded.contains(DVec3::new(0.0, 1.0, 0.0)));
+    assert!(!rounded.contains(DVec3::new(0.0, 1.01, 0.0)));
+    assert!((rounded.distance_to_point(DVec3::new(0.0, 3.0, 0.0)) - 2.0).abs() < 1e-12);
+    assert!((rounded.distance_to_point(DVec3::new(5.0, 0.0, 0.0)) - 2.0).abs() < 1e-12);
+    let volume = rounded.to_convex_hull(3).unwrap().volume();
+    assert!(volume < capsule_volume && volume > 0.98 * capsule_volume);
+
+    // Points and boxes have no radius, so the rounding is only as large as the smallest radius.
+    let rounded = RoundedConvexHull::try_new(&mixed, 1).unwrap();
+    assert_eq!(rounded.radius, 0.0);
+    assert!(rounded.contains(DVec3::new(0.0, 5.0, 0.0)));
+    let rounded = RoundedConvexHull::try_new(&mixed[1..2], 1).unwrap();
+    assert_eq!(rounded.core.len(), 2);
+    assert_eq!(rounded.radius, 0.5);
+    assert_eq!(
+        RoundedConvexHull::try_new(&[], 1).unwrap_err(),
+        ErrorKind::Empty
+    );
+
+    // Large subdivisions are capped, and coordinates that are not a number do not panic.
+    let rounded = RoundedConvexHull::try_new(&spheres, usize::MAX).unwrap();
+    assert_eq!(rounded.core.len(), 2);
+    let rounded = RoundedConvexHull::try_new(
+        &[
+            Primitive::Point(DVec3::ZERO),
+            Primitive::Point(DVec3::new(f64::NAN, 0.0, 0.0)),
+        ],
+        1,
+    )
+    .unwrap();
+    assert_eq!(rounded.core.len(), 2);
+}
+
+#[test]
+fn delaunay_test() {
+    use rand::prelude::{Distribution, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let dist = rand::distr::StandardUniform;
+
+    // The tetrahedra fill the convex hull, and no point is inside the circumsphere of a tetrahedron.
+    let points: Vec<DVec3> = (0..200)
+        .map(|_| {
+            DVec3::new(
+                dist.sample(&mut rng),
+                dist.sample(&mut rng),
+                dist.sample(&mut rng),
+            )
+        })
+        .collect();
+    let tetrahedra = delaunay_3d(&points).unwrap();
+    let mut volume = 0.0;
+    for [a, b, c, d] in &tetrahedra {
+        let [pa, pb, pc, pd] = [a, b, c, d].map(|i| points[*i]);
+        let tetrahedron_volume = (pb - pa).cross(pc - pa).dot(pd - pa) / 6.0;
+        assert!(tetrahedron_volume > 0.0);
+        volume += tetrahedron_volume;
+
+        for (i, p) in points.iter().enumerate() {
+            if ![*a, *b, *c, *d].contains(&i) {
+                let [pa, pb, pc, pd, p] = [pb, pa, pc, pd, *p].map(|p| p.to_robust());
+                assert!(robust::insphere(pa, pb, pc, pd, p) <= 0.0);
+            }
+        }
+    }
+    let hull_volume = ConvexHull::try_new(&points, None).unwrap().volume();
+    assert!((volume - hull_volume).abs() < 1e-12);
+
+    // The corners of a cube are cospherical, and the center is inside every circumsphere.
+    let mut cube: Vec<DVec3> = (0..8)
+        .map(|i| DVec3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+        .collect();
+    let tetrahedra = delaunay_3d(&cube).unwrap();
+    let volume: f64 = tetrahedra
+        .iter()
+        .map(|t| {
+            let [pa, pb, pc, pd] = t.map(|i| cube[i]);
+            (pb - pa).cross(pc - pa).dot(pd - pa) / 6.0
+        })
+        .sum();
+    assert!((volume - 1.0).abs() < 1e-12);
+    cube.push(DVec3::splat(0.5));
+    let tetrahedra = delaunay_3d(&cube).unwrap();
+    assert_eq!(tetrahedra.len(), 12);
+    assert!(tetrahedra.iter().all(|t| t.contains(&8)));
+
+    assert_eq!(delaunay_3d(&[]).unwrap_err(), ErrorKind::Empty);
+    assert_eq!(
+        delaunay_3d(&cube[..4]).unwrap_err(),
+        ErrorKind::DegenerateInput(DegenerateInput::Coplanar)
+    );
+
+    // The same holds for triangles in the plane.
+    let points: Vec<DVec2> = (0..200)
+        .map(|_| DVec2::new(dist.sample(&mut rng), dist.sample(&mut rng)))
+        .collect();
+    let triangles = delaunay_2d(&points).unwrap();
+    let mut area = 0.0;
+    for [a, b, c] in &triangles {
+        let [pa, pb, pc] = [a, b, c].map(|i| points[*i]);
+        let triangle_area = (pb - pa).perp_dot(pc - pa) / 2.0;
+        assert!(triangle_area > 0.0);
+        area += triangle_area;
+
+        for (i, p) in points.iter().enumerate() {
+            if ![*a, *b, *c].contains(&i) {
+                let [pa, pb, pc, p] = [pa, pb, pc, *p].map(|p| robust::Coord { x: p.x, y: p.y });
+                assert!(robust::incircle(pa, pb, pc, p) <= 0.0);
+            }
+        }
+    }
+    let hull = polygon::convex_hull_2d(&points);
+    let hull_area: f64 = (0..hull.len())
+        .map(|i| points[hull[i]].perp_dot(points[hull[(i + 1) % hull.len()]]) / 2.0)
+        .sum();
+    assert!((area - hull_area).abs() < 1e-12);
+}
+
+#[test]
+fn delaunay_2d_cocircular_test() {
+    // Every unit square of a grid has four cocircular corners, which can be split either way.
+    let grid: Vec<DVec2> = (0..100)
+        .map(|i| DVec2::new((i % 10) as f64, (i / 10) as f64))
+        .collect();
+    let triangles = delaunay_2d(&grid).unwrap();
+    assert_eq!(triangles.len(), 162);
+    for [a, b, c] in &triangles {
+        let [pa, pb, pc] = [a, b, c].map(|i| grid[*i]);
+        assert_eq!((pb - pa).perp_dot(pc - pa), 1.0);
+
+        for (i, p) in grid.iter().enumerate() {
+            if ![*a, *b, *c].contains(&i) {
+                let [pa, pb, pc, p] = [pa, pb, pc, *p].map(|p| robust::Coord { x: p.x, y: p.y });
+                assert!(robust::incircle(pa, pb, pc, p) <= 0.0);
+            }
+        }
+    }
+
+    // Points that are all on the same circle lift to points on the same plane.
+    let square = [DVec2::ZERO, DVec2::X, DVec2::ONE, DVec2::Y];
+    assert_eq!(delaunay_2d(&square).unwrap().len(), 2);
+    let polygon: Vec<DVec2> = (0..12)
+        .map(|i| DVec2::from_angle(i as f64 * std::f64::consts::TAU / 12.0))
+        .collect();
+    assert_eq!(delaunay_2d(&polygon).unwrap().len(), 10);
+    assert_eq!(delaunay_2d(&square[..3]).unwrap(), vec![[0, 1, 2]]);
+
+    // The indices refer to the input, and duplicate points are only used once.
+    let points = [
+        DVec2::ZERO,
+        DVec2::X,
+        DVec2::X,
+        DVec2::Y,
+        DVec2::splat(0.25),
+    ];
+    let triangles = delaunay_2d(&points).unwrap();
+    assert_eq!(triangles.len(), 3);
+    assert!(triangles.iter().all(|t| t.contains(&4)));
+    assert!(triangles.iter().all(|t| !t.contains(&1)) || triangles.iter().all(|t| !t.contains(&2)));
+
+    assert_eq!(delaunay_2d(&[]).unwrap_err(), ErrorKind::Empty);
+    assert_eq!(
+        delaunay_2d(&[DVec2::ZERO, DVec2::X, DVec2::new(2.0, 0.0)]).unwrap_err(),
+        ErrorKind::DegenerateInput(DegenerateInput::Collinear)
+    );
+    assert_eq!(
+        delaunay_2d(&[DVec2::ONE; 3]).unwrap_err(),
+        ErrorKind::DegenerateInput(DegenerateInput::Coincident)
+    );
+}
+
+#[test]
+fn voronoi_test() {
+    use rand::prelude::{Distribution, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let dist = rand::distr::StandardUniform;
+
+    // The cells fill the box, and each cell is closer to its own point than to any other point.
+    let points: Vec<DVec3> = (0..50)
+        .map(|_| {
+            DVec3::new(
+                dist.sample(&mut rng),
+                dist.sample(&mut rng),
+                dist.sample(&mut rng),
+            )
+        })
+        .collect();
+    let cells = voronoi_cells_3d(&points, DVec3::ZERO, DVec3::ONE);
+    assert_eq!(cells.len(), points.len());
+    let mut volume = 0.0;
+    for (i, cell) in cells.into_iter().enumerate() {
+        let cell = cell.unwrap();
+        for vertex in &cell.points {
+            let distance = vertex.distance(points[i]);
+            assert!(points
+                .iter()
+                .all(|p| p.distance(*vertex) >= distance - 1e-9));
+        }
+        volume += cell.volume();
+    }
+    assert!((volume - 1.0).abs() < 1e-9);
+
+    // Too few points for a tetrahedralization, and a duplicate point.
+    let points = [DVec3::splat(0.25), DVec3::splat(0.75), DVec3::splat(0.75)];
+    let cells = voronoi_cells_3d(&points, DVec3::ZERO, DVec3::ONE);
+    for cell in cells {
+        assert!((cell.unwrap().volume() - 0.5).abs() < 1e-12);
+    }
+    let cells = voronoi_cells_3d(&points, DVec3::splat(0.6), DVec3::ONE);
+    assert!(cells[0].is_none() && cells[1].is_some());
+
+    let points: Vec<DVec2> = (0..100)
+        .map(|_| DVec2::new(dist.sample(&mut rng), dist.sample(&mut rng)))
+        .collect();
+    let cells = voronoi_cells_2d(&points, DVec2::ZERO, DVec2::ONE);
+    let mut area = 0.0;
+    for (i, cell) in cells.iter().enumerate() {
+        for (j, vertex) in cell.iter().enumerate() {
+            let distance = vertex.distance(points[i]);
+            assert!(points
+                .iter()
+                .all(|p| p.distance(*vertex) >= distance - 1e-9));
+            area += vertex.perp_dot(cell[(j + 1) % cell.len()]) / 2.0;
+        }
+    }
+    assert!((area - 1.0).abs() < 1e-9);
+
+    // Collinear points split the box into strips.
+    let points = [
+        DVec2::new(0.25, 0.5),
+        DVec2::new(0.5, 0.5),
+        DVec2::new(0.75, 0.5),
+    ];
+    let cells = voronoi_cells_2d(&points, DVec2::ZERO, DVec2::ONE);
+    assert_eq!(cells[1].len(), 4);
+    assert!(cells[1].iter().all(|p| p.x == 0.375 || p.x == 0.625));
+    let cells = voronoi_cells_2d(&points, DVec2::new(0.7, 0.0), DVec2::ONE);
+    assert!(cells[0].is_empty() && cells[1].is_empty() && cells[2].len() == 4);
+}
+
+#[test]
+fn convex_hull_nd_test() {
+    use rand::prelude::{Distribution, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let dist = rand::distr::StandardUniform;
+
+    // The same convex hull as in 3D.
+    let points: Vec<DVec3> = (0..100)
+        .map(|_| {
+            DVec3::new(
+                dist.sample(&mut rng),
+                dist.sample(&mut rng),
+                dist.sample(&mut rng),
+            )
+        })
+        .collect();
+    let arrays: Vec<[f64; 3]> = points.iter().map(|p| p.to_array()).collect();
+    let c_hull_nd = ConvexHullNd::try_new(&arrays).unwrap();
+    let c_hull = ConvexHull::try_new(&points, None).unwrap();
+    let vertices: Vec<[f64; 3]> = c_hull_nd
+        .vertex_indices()
+        .into_iter()
+        .map(|i| arrays[i])
+        .collect();
+    let mut expected: Vec<[f64; 3]> = c_hull.points.iter().map(|p| p.to_array()).collect();
+    expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut vertices = vertices;
+    vertices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(vertices, expected);
+    assert!((c_hull_nd.volume() - c_hull.volume()).abs() < 1e-12);
+
+    // A 4D grid has many points on the same hyperplanes, which need the exact orientation tests.
+    let grid: Vec<[f64; 4]> = (0..81)
+        .map(|i| [i % 3, i / 3 % 3, i / 9 % 3, i / 27].map(|x| x as f64 * 0.1))
+        .collect();
+    let c_hull = ConvexHullNd::try_new(&grid).unwrap();
+    assert!((c_hull.volume() - 0.0016).abs() < 1e-15);
+    assert!(grid.iter().all(|p| c_hull.contains(*p)));
+    assert!(!c_hull.contains([0.1, 0.1, 0.1, 0.2f64.next_up()]));
+    for corner in (0..16).map(|i| [i & 1, i >> 1 & 1, i >> 2 & 1, i >> 3].map(|x| x as usize * 2)) {
+        let index = corner[0] + 3 * corner[1] + 9 * corner[2] + 27 * corner[3];
+        assert!(c_hull.vertex_indices().contains(&index));
+    }
+
+    // The cross-polytope in 5D has a facet in each orthant.
+    let cross_polytope: Vec<[f64; 5]> = (0..10)
+        .map(|i| {
+            let mut point = [0.0; 5];
+            point[i / 2] = if i % 2 == 0 { 1.0 } else { -1.0 };
+            point
+        })
+        .collect();
+    let c_hull = ConvexHullNd::try_new(&cross_polytope).unwrap();
+    assert_eq!(c_hull.facets().len(), 32);
+    assert!((c_hull.volume() - 32.0 / 120.0).abs() < 1e-15);
+    assert!(c_hull.contains([0.25, 0.25, 0.25, 0.25, 0.0]));
+    assert!(!c_hull.contains([0.25, 0.25, 0.25, 0.25, f64::MIN_POSITIVE]));
+
+    assert_eq!(
+        ConvexHullNd::<4>::try_new(&[]).unwrap_err(),
+        ErrorKind::Empty
+    );
+    assert_eq!(
+        ConvexHullNd::try_new(&[0.0, 1.0, 2.0, 3.0, 4.0].map(|x| [x; 4])).unwrap_err(),
+        ErrorKind::DegenerateInput(DegenerateInput::Collinear)
+    );
+    assert_eq!(
+        ConvexHullNd::try_new(&grid[..27]).unwrap_err(),
+        ErrorKind::DegenerateInput(DegenerateInput::Coplanar)
+    );
+    assert_eq!(
+        ConvexHullNd::try_new(&[[1.0; 4]; 6]).unwrap_err(),
+        ErrorKind::DegenerateInput(DegenerateInput::Coincident)
+    );
+}
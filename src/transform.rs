@@ -0,0 +1,62 @@
+//! Affine transforms of convex hulls.
+
+use glam::{DAffine3, DMat4, DVec4};
+
+use crate::{ConvexHull, ErrorKind};
+
+impl ConvexHull {
+    /// Transforms the convex hull in place by the given affine transform matrix,
+    /// without recomputing the convex hull.
+    ///
+    /// The points are transformed by the matrix, and the face normals by its inverse transpose,
+    /// which keeps them perpendicular to the faces under non-uniform scaling. A transform that mirrors
+    /// the convex hull turns its faces inside out, so their winding is reversed.
+    ///
+    /// ## Errors
+    /// If the matrix is not affine, or is not invertible, in which case the convex hull is left unchanged.
+    pub fn transform(&mut self, transform: &DMat4) -> Result<(), ErrorKind> {
+        if transform.row(3) != DVec4::W {
+            return Err(ErrorKind::InvalidTransform);
+        }
+        self.transform_affine(&DAffine3::from_mat4(*transform))
+    }
+
+    /// Returns the convex hull transformed by the given affine transform, without recomputing it.
+    ///
+    /// See [`ConvexHull::transform`].
+    ///
+    /// ## Errors
+    /// If the transform is not invertible.
+    pub fn transformed(&self, transform: &DAffine3) -> Result<Self, ErrorKind> {
+        let mut c_hull = self.clone();
+        c_hull.transform_affine(transform)?;
+        Ok(c_hull)
+    }
+
+    fn transform_affine(&mut self, transform: &DAffine3) -> Result<(), ErrorKind> {
+        let matrix = transform.matrix3;
+        let determinant = matrix.determinant();
+
+        // A nearly singular matrix would flatten the convex hull.
+        let scale = matrix.x_axis.length() * matrix.y_axis.length() * matrix.z_axis.length();
+        if !transform.is_finite() || determinant.abs() <= f64::EPSILON * scale {
+            return Err(ErrorKind::InvalidTransform);
+        }
+
+        for point in &mut self.points {
+            *point = transform.transform_point3(*point);
+        }
+
+        // The normals are cross products of the face edges, which scale with the determinant.
+        let normal_matrix = matrix.inverse().transpose() * determinant.abs();
+        for face in self.faces.values_mut() {
+            if determinant < 0.0 {
+                face.indices.swap(0, 1);
+            }
+            face.normal = normal_matrix * face.normal;
+            face.distance_from_origin = face.normal.dot(self.points[face.indices[0]]);
+        }
+
+        Ok(())
+    }
+}
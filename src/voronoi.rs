@@ -0,0 +1,123 @@
+//! Voronoi cells from the neighbors in Delaunay triangulations.
+
+use glam::{DVec2, DVec3};
+
+use std::collections::BTreeSet;
+
+use crate::polygon::clip_polygon;
+use crate::{delaunay_2d, delaunay_3d, ConvexHull};
+
+/// Computes the Voronoi cells of the given points in the plane, clipped to the box from `min` to `max`.
+///
+/// The cell of each point is the region closer to it than to any other point. It is computed by clipping
+/// the box with the bisectors between the point and its neighbors in the [Delaunay triangulation](delaunay_2d),
+/// or with the bisectors to every other point if the points are too few or collinear for a triangulation.
+///
+/// The cells are returned in the same order as the points, each as a convex polygon in counterclockwise order.
+/// A cell is empty if it does not overlap the box. Duplicate points share the same cell.
+#[must_use]
+pub fn voronoi_cells_2d(points: &[DVec2], min: DVec2, max: DVec2) -> Vec<Vec<DVec2>> {
+    let neighbors = match delaunay_2d(points) {
+        Ok(triangles) => delaunay_neighbors(points, &triangles),
+        Err(_) => all_neighbors(points),
+    };
+
+    let rectangle = [min, DVec2::new(max.x, min.y), max, DVec2::new(min.x, max.y)];
+
+    points
+        .iter()
+        .zip(neighbors)
+        .map(|(point, neighbors)| {
+            let mut cell = rectangle.to_vec();
+            for neighbor in neighbors {
+                let normal = points[neighbor] - *point;
+                let offset = normal.dot((*point + points[neighbor]) * 0.5);
+                cell = clip_polygon(&cell, normal, offset);
+            }
+
+            if cell.len() < 3 {
+                cell.clear();
+            }
+            cell
+        })
+        .collect()
+}
+
+/// Computes the Voronoi cells of the given points, clipped to the box from `min` to `max`.
+///
+/// The cell of each point is the region closer to it than to any other point. It is computed by
+/// [clipping](ConvexHull::clip) the box with the bisector planes between the point and its neighbors
+/// in the [Delaunay tetrahedralization](delaunay_3d), or with the bisector planes to every other point
+/// if the points are too few or coplanar for a tetrahedralization.
+///
+/// The cells are returned in the same order as the points. A cell is `None` if it has no volume
+/// inside of the box. Duplicate points share the same cell.
+#[must_use]
+pub fn voronoi_cells_3d(points: &[DVec3], min: DVec3, max: DVec3) -> Vec<Option<ConvexHull>> {
+    let neighbors = match delaunay_3d(points) {
+        Ok(tetrahedra) => delaunay_neighbors(points, &tetrahedra),
+        Err(_) => all_neighbors(points),
+    };
+
+    let corners: Vec<DVec3> = (0..8)
+        .map(|i| {
+            DVec3::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        })
+        .collect();
+    let Ok(cuboid) = ConvexHull::try_new(&corners, None) else {
+        return vec![None; points.len()];
+    };
+
+    points
+        .iter()
+        .zip(neighbors)
+        .map(|(point, neighbors)| {
+            let mut cell = cuboid.clone();
+            for neighbor in neighbors {
+                let normal = points[neighbor] - *point;
+                let offset = normal.dot((*point + points[neighbor]) * 0.5);
+                cell = cell.clip(normal, offset)?;
+            }
+            Some(cell)
+        })
+        .collect()
+}
+
+/// Computes the neighbors of each point in a Delaunay triangulation with the given `simplices`.
+///
+/// Points that are left out of the triangulation duplicate another point, and get the same neighbors.
+fn delaunay_neighbors<P: PartialEq, const N: usize>(
+    points: &[P],
+    simplices: &[[usize; N]],
+) -> Vec<BTreeSet<usize>> {
+    let mut neighbors = vec![BTreeSet::new(); points.len()];
+    for simplex in simplices {
+        for a in simplex {
+            neighbors[*a].extend(simplex.iter().filter(|b| *b != a));
+        }
+    }
+
+    for i in 0..points.len() {
+        if neighbors[i].is_empty() {
+            if let Some(j) =
+                (0..points.len()).find(|j| !neighbors[*j].is_empty() && points[*j] == points[i])
+            {
+                neighbors[i] = neighbors[j].clone();
+            }
+        }
+    }
+
+    neighbors
+}
+
+/// Lists every other point as a neighbor of each point, skipping duplicates of the point itself.
+fn all_neighbors<P: PartialEq>(points: &[P]) -> Vec<BTreeSet<usize>> {
+    points
+        .iter()
+        .map(|point| (0..points.len()).filter(|j| points[*j] != *point).collect())
+        .collect()
+}